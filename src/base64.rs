@@ -0,0 +1,44 @@
+use super::MicroStr;
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encodes bytes as a standard padded base64 `MicroStr`.
+///
+/// Every 3 input bytes take 4 characters. If `OUT` is too small, the output is **truncated**
+/// to the 4-character groups that fit entirely.
+///
+/// # Example
+///
+/// ```rust
+/// use microstr::*;
+/// let s: MicroStr<16> = base64_encode(b"user:pass");
+/// assert_eq!(s.as_str(), "dXNlcjpwYXNz");
+///
+/// let short: MicroStr<6> = base64_encode(b"user:pass");
+/// assert_eq!(short.as_str(), "dXNl"); // Truncated
+/// ```
+pub const fn base64_encode<const OUT: usize>(data: &[u8]) -> MicroStr<OUT> {
+    let mut result = MicroStr::new();
+    let mut i = 0;
+    while i < data.len() && result.extra_capacity() >= 4 {
+        let remaining = data.len() - i;
+        let b0 = data[i];
+        let b1 = if remaining > 1 { data[i + 1] } else { 0 };
+        let b2 = if remaining > 2 { data[i + 2] } else { 0 };
+
+        let c0 = BASE64_ALPHABET[(b0 >> 2) as usize];
+        let c1 = BASE64_ALPHABET[((b0 & 0b11) << 4 | b1 >> 4) as usize];
+        let c2 = if remaining > 1 { BASE64_ALPHABET[((b1 & 0b1111) << 2 | b2 >> 6) as usize] } else { b'=' };
+        let c3 = if remaining > 2 { BASE64_ALPHABET[(b2 & 0b11_1111) as usize] } else { b'=' };
+
+        // SAFETY: 4 ASCII bytes fit, checked by the loop condition
+        unsafe {
+            result.push_unchecked(c0 as char);
+            result.push_unchecked(c1 as char);
+            result.push_unchecked(c2 as char);
+            result.push_unchecked(c3 as char);
+        }
+        i += 3;
+    }
+    result
+}
@@ -0,0 +1,62 @@
+use core::ops::{Deref, DerefMut};
+use super::{MicroStr, valid_utf8_prefix_len};
+
+/// A guard giving mutable access to the content bytes of a `MicroStr`.
+///
+/// Returned by [`MicroStr::bytes_mut_guard`]. When dropped, it re-validates the content
+/// and cuts the length at the first invalid UTF-8 byte, so the string stays valid
+/// whatever was written through the guard.
+///
+/// While the guard is alive, the string itself is empty, so leaking the guard
+/// (e.g. with `mem::forget`) leaves an empty, valid string.
+pub struct ByteGuard<'a, const CAP: usize> {
+    s: &'a mut MicroStr<CAP>,
+    len: usize,
+}
+
+impl<const CAP: usize> MicroStr<CAP> {
+    /// Returns a guard for safe bulk byte edits of the content.
+    ///
+    /// The guard derefs to `&mut [u8]` of length [`MicroStr::bytes_len`]. On drop, the length
+    /// is cut to the longest valid UTF-8 prefix.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("abcd");
+    /// s.bytes_mut_guard()[1..3].copy_from_slice(b"XY");
+    /// assert_eq!(s.as_str(), "aXYd");
+    ///
+    /// s.bytes_mut_guard()[2] = 0xFF;
+    /// assert_eq!(s.as_str(), "aX");
+    /// ```
+    #[inline]
+    pub fn bytes_mut_guard(&mut self) -> ByteGuard<'_, CAP> {
+        let len = self.len;
+        self.len = 0;
+        ByteGuard { s: self, len }
+    }
+}
+
+impl<const CAP: usize> Deref for ByteGuard<'_, CAP> {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        &self.s.buffer[..self.len]
+    }
+}
+
+impl<const CAP: usize> DerefMut for ByteGuard<'_, CAP> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [u8] {
+        &mut self.s.buffer[..self.len]
+    }
+}
+
+impl<const CAP: usize> Drop for ByteGuard<'_, CAP> {
+    fn drop(&mut self) {
+        self.s.len = valid_utf8_prefix_len(&self.s.buffer, self.len);
+    }
+}
@@ -0,0 +1,80 @@
+use super::MicroStr;
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Encodes bytes as a lowercase hex `MicroStr`.
+///
+/// Each byte takes two characters. If `OUT` is too small, the output is **truncated**
+/// to the bytes that fit entirely (a byte is never half-encoded).
+///
+/// # Example
+///
+/// ```rust
+/// use microstr::*;
+/// let s: MicroStr<8> = to_hex(&[0xde, 0xad, 0xbe, 0xef]);
+/// assert_eq!(s.as_str(), "deadbeef");
+///
+/// let short: MicroStr<5> = to_hex(&[0xde, 0xad, 0xbe, 0xef]);
+/// assert_eq!(short.as_str(), "dead"); // Truncated
+/// ```
+pub const fn to_hex<const OUT: usize>(bytes: &[u8]) -> MicroStr<OUT> {
+    let mut result = MicroStr::new();
+    let mut i = 0;
+    while i < bytes.len() && result.extra_capacity() >= 2 {
+        let hi = HEX_DIGITS[(bytes[i] >> 4) as usize] as char;
+        let lo = HEX_DIGITS[(bytes[i] & 0x0f) as usize] as char;
+        // SAFETY: 2 ASCII bytes fit, checked by the loop condition
+        unsafe {
+            result.push_unchecked(hi);
+            result.push_unchecked(lo);
+        }
+        i += 1;
+    }
+    result
+}
+
+impl<const CAP: usize> MicroStr<CAP> {
+    /// Decodes hex content into a byte array.
+    ///
+    /// Both lowercase and uppercase digits are accepted.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok([u8; N])` with the decoded bytes.
+    /// - `Err(())` if the content is not exactly `2 * N` hex digits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("DEADbeef");
+    /// assert_eq!(s.from_hex(), Ok([0xde, 0xad, 0xbe, 0xef]));
+    /// assert_eq!(s.from_hex::<2>(), Err(()));
+    /// ```
+    #[allow(clippy::result_unit_err)]
+    pub const fn from_hex<const N: usize>(&self) -> Result<[u8; N], ()> {
+        if self.len != N * 2 {
+            return Err(());
+        }
+        let mut result = [0; N];
+        let mut i = 0;
+        while i < N {
+            let (Some(hi), Some(lo)) = (hex_value(self.buffer[i * 2]), hex_value(self.buffer[i * 2 + 1])) else {
+                return Err(());
+            };
+            result[i] = hi << 4 | lo;
+            i += 1;
+        }
+        Ok(result)
+    }
+}
+
+/// Returns value of hex digit
+const fn hex_value(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
@@ -14,7 +14,9 @@
 //! ## Cargo Features
 //!
 //! - `std` *(optional)*: Enables `Display`, `Debug`, `From<String>`, and other std traits.
-//! - `serde` *(optional, requires `std`)*: Enables JSON serialization/deserialization.
+//! - `serde` *(optional)*: Enables `Serialize` and `Deserialize`, from strings or UTF-8 bytes.
+//! - `base64` *(optional)*: Enables [`base64_encode`].
+//! - `unicode-segmentation` *(optional)*: Enables grapheme cluster methods like `graphemes`.
 //!
 //! ## Example
 //!
@@ -33,13 +35,40 @@ mod tests;
 mod std_only;
 #[macro_use]
 mod macros;
+mod byte_guard;
+mod hex;
+mod list;
+mod micro_fmt;
+mod transaction;
+mod trimmed;
+mod writer;
+#[cfg(feature = "base64")]
+mod base64;
+#[cfg(feature = "unicode-segmentation")]
+mod segmentation;
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+pub use byte_guard::ByteGuard;
+pub use hex::to_hex;
+pub use list::MicroStrList;
+pub use micro_fmt::MicroFmt;
+pub use transaction::Transaction;
+pub use trimmed::Trimmed;
+pub use writer::MicroStrWriter;
+#[cfg(feature = "base64")]
+pub use base64::base64_encode;
+#[cfg(feature = "serde")]
+pub use serde_impl::AsBytes;
 
 use core::{
-    cmp::PartialEq, 
-    fmt, 
-    ops::{Deref, DerefMut}, 
+    char::{decode_utf16, DecodeUtf16Error},
+    cmp::{Ordering, PartialEq}, 
+    fmt::{self, Write as _}, 
+    hash::{Hash, Hasher},
+    ops::{Bound, Deref, DerefMut, Index, IndexMut, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive}, 
     ptr,
-    str::{from_utf8_unchecked, from_utf8_unchecked_mut}
+    str::{FromStr, from_utf8, from_utf8_unchecked, from_utf8_unchecked_mut, Utf8Error}
 };
 
 /// A fixed-capacity, stack-allocated string with UTF-8 support.
@@ -65,7 +94,8 @@ use core::{
 ///
 /// # Type Parameters
 ///
-/// - `CAP`: The maximum number of bytes this string can hold (must be at least 1).
+/// - `CAP`: The maximum number of bytes this string can hold. `MicroStr<0>` is allowed:
+///   it is always empty, and no method ever writes to its buffer.
 ///
 /// # Notes
 ///
@@ -167,6 +197,73 @@ impl<const CAP: usize> MicroStr<CAP>
         result
     }
 
+    /// Constructs a `MicroStr` by concatenating a slice of `MicroStr`s.
+    ///
+    /// Usable in const context. If the parts don't fit, the result is **truncated**,
+    /// ensuring UTF-8 validity (does not split multi-byte characters).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let parts = [microstr!("ab", 4), microstr!("cd", 4), microstr!("ef", 4)];
+    /// let s = MicroStr::<5>::concat_all(&parts);
+    /// assert_eq!(s.as_str(), "abcde"); // Truncated
+    /// ```
+    pub const fn concat_all<const B: usize>(parts: &[MicroStr<B>]) -> Self {
+        let mut result = Self::new();
+        let mut i = 0;
+        while i < parts.len() {
+            if result.push_str(parts[i].as_str()).is_err() {
+                break;
+            }
+            i += 1;
+        }
+        result
+    }
+
+    /// Constructs a `MicroStr` from an array of chars.
+    ///
+    /// Usable in const context. If the chars don't fit, the result is **truncated**
+    /// at the last char that fits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// const S: MicroStr<4> = MicroStr::from_chars(['ё', 'ж', '!']);
+    /// assert_eq!(S.as_str(), "ёж"); // Truncated
+    /// ```
+    pub const fn from_chars<const N: usize>(chars: [char; N]) -> Self {
+        let mut result = Self::new();
+        let mut i = 0;
+        while i < N {
+            if result.push(chars[i]).is_err() {
+                break;
+            }
+            i += 1;
+        }
+        result
+    }
+
+    /// Constructs a `MicroStr` from a string slice, truncating it if it doesn't fit.
+    ///
+    /// Same as [`MicroStr::from_const`], named for the intent: unlike [`MicroStr::from_str`],
+    /// always returns the (possibly truncated) string, without a `Result`.
+    /// Truncation never splits multi-byte characters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// const S: MicroStr<5> = MicroStr::from_str_lossy("Привет");
+    /// assert_eq!(S.as_str(), "Пр");
+    /// ```
+    #[inline]
+    pub const fn from_str_lossy(s: &str) -> Self {
+        Self::from_const(s)
+    }
+
     /// Constructs a `MicroStr` from a raw byte buffer.
     ///
     /// Copies up to `min(N, CAP)` bytes from the input buffer `buf` into the `MicroStr`.
@@ -236,6 +333,115 @@ impl<const CAP: usize> MicroStr<CAP>
         }
     }
 
+    /// Constructs a `MicroStr` from format arguments.
+    ///
+    /// If the formatted string is longer than the capacity, it is **truncated** to fit,
+    /// ensuring UTF-8 validity (does not split multi-byte characters).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let temp = 21.5;
+    /// let s = MicroStr::<16>::from_fmt(format_args!("t = {temp}°C"));
+    /// assert_eq!(s.as_str(), "t = 21.5°C");
+    ///
+    /// let s = MicroStr::<4>::from_fmt(format_args!("{}", 123456));
+    /// assert_eq!(s.as_str(), "1234"); // Truncated
+    /// ```
+    pub fn from_fmt(args: fmt::Arguments<'_>) -> Self {
+        let mut result = Self::new();
+        let _ = result.write_fmt(args);
+        result
+    }
+
+    /// Constructs a `MicroStr` from an ASCII-only string slice.
+    ///
+    /// Useful for protocols that mandate ASCII content.
+    /// If the input is longer than the capacity, it is **truncated** to fit.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(MicroStr)` if `s` is ASCII.
+    /// - `Err(())` if `s` contains any non-ASCII byte.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = MicroStr::<8>::try_from_ascii("GET /").unwrap();
+    /// assert_eq!(s.as_str(), "GET /");
+    /// assert!(MicroStr::<8>::try_from_ascii("Привет").is_err());
+    /// ```
+    #[allow(clippy::result_unit_err)]
+    pub const fn try_from_ascii(s: &str) -> Result<Self, ()> {
+        if !s.is_ascii() {
+            return Err(());
+        }
+        Ok(Self::from_const(s))
+    }
+
+    /// Constructs a `MicroStr` from ASCII bytes, skipping UTF-8 validation.
+    ///
+    /// ASCII is always valid UTF-8, so only `< 0x80` is checked for every byte.
+    /// If the input is longer than the capacity, it is **truncated** to fit.
+    ///
+    /// # Returns
+    ///
+    /// `Some(MicroStr)`, or `None` if any byte is not ASCII.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = MicroStr::<8>::from_ascii(b"AT+OK\r\n").unwrap();
+    /// assert_eq!(s.as_str(), "AT+OK\r\n");
+    /// assert!(MicroStr::<8>::from_ascii(b"caf\xC3\xA9").is_none());
+    /// ```
+    pub const fn from_ascii(bytes: &[u8]) -> Option<Self> {
+        if !bytes.is_ascii() {
+            return None;
+        }
+        let mut result = Self::new();
+        let len = const_min(bytes.len(), CAP);
+        // SAFETY: `len` fits both buffers, and ASCII bytes are valid UTF-8 at any cut
+        unsafe { ptr::copy_nonoverlapping(bytes.as_ptr(), result.as_mut_ptr(), len) };
+        result.len = len;
+        Some(result)
+    }
+
+    /// Constructs a `MicroStr` from UTF-16 code units.
+    ///
+    /// If the decoded string is longer than the capacity, it is **truncated** to fit,
+    /// ensuring UTF-8 validity (does not split multi-byte characters).
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(MicroStr)` with the decoded content.
+    /// - `Err(DecodeUtf16Error)` if `units` contains an unpaired surrogate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let units = [0x0048, 0x0069, 0xD83E, 0xDD80]; // "Hi🦀"
+    /// let s = MicroStr::<8>::from_utf16(&units).unwrap();
+    /// assert_eq!(s.as_str(), "Hi🦀");
+    /// assert!(MicroStr::<8>::from_utf16(&[0xD83E]).is_err());
+    /// ```
+    pub fn from_utf16(units: &[u16]) -> Result<Self, DecodeUtf16Error> {
+        let mut result = Self::new();
+        for ch in decode_utf16(units.iter().copied()) {
+            let ch = ch?;
+            if !result.can_push(ch) {
+                break;
+            }
+            // SAFETY: checked length
+            unsafe { result.push_unchecked(ch) };
+        }
+        Ok(result)
+    }
+
     /* ##### GETTERS ##### */
 
     /// Returns a raw pointer to the first byte of the internal buffer.
@@ -274,6 +480,29 @@ impl<const CAP: usize> MicroStr<CAP>
         self.buffer.as_mut_ptr()
     }
 
+    /// Returns the two raw pointers spanning the content.
+    ///
+    /// The end pointer is one past the last byte of the content, like in
+    /// [`slice::as_ptr_range`](https://doc.rust-lang.org/std/primitive.slice.html#method.as_ptr_range).
+    /// Useful for FFI taking begin/end pointers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("Hi", 10);
+    /// let range = s.as_ptr_range();
+    /// assert_eq!(range.start, s.as_ptr());
+    /// assert_eq!(range.end as usize - range.start as usize, 2);
+    /// ```
+    #[inline]
+    pub const fn as_ptr_range(&self) -> Range<*const u8> {
+        let start = self.buffer.as_ptr();
+        // SAFETY: `len <= CAP`, so the end is at most one past the buffer
+        let end = unsafe { start.add(self.len) };
+        start..end
+    }
+
     /// Returns the total capacity in bytes.
     ///
     /// This is the maximum number of bytes the string can hold.
@@ -290,6 +519,37 @@ impl<const CAP: usize> MicroStr<CAP>
         CAP
     }
 
+    /// Returns the total capacity in bytes.
+    ///
+    /// Same as [`MicroStr::capacity`], named explicitly. The capacity in chars depends on
+    /// the content: from `CAP / 4` chars (4-byte chars only) up to `CAP` ASCII chars.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s: MicroStr<32> = MicroStr::new();
+    /// assert_eq!(s.byte_capacity(), 32);
+    /// ```
+    #[inline]
+    pub const fn byte_capacity(&self) -> usize {
+        CAP
+    }
+
+    /// Returns the maximum number of ASCII chars the string can hold, which is `CAP`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s: MicroStr<32> = MicroStr::new();
+    /// assert_eq!(s.max_ascii_chars(), 32);
+    /// ```
+    #[inline]
+    pub const fn max_ascii_chars(&self) -> usize {
+        CAP
+    }
+
     /// Returns the number of unused bytes available for writing.
     ///
     /// Equivalent to `self.capacity() - self.bytes_len()`.
@@ -306,6 +566,38 @@ impl<const CAP: usize> MicroStr<CAP>
         CAP - self.len
     }
 
+    /// Returns `true` if `ch` fits into the unused capacity.
+    ///
+    /// A predicate counterpart of [`MicroStr::push`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("Hi", 5);
+    /// assert!(s.can_push('ш'));  // 2 bytes
+    /// assert!(!s.can_push('🦀')); // 4 bytes
+    /// ```
+    #[inline]
+    pub const fn can_push(&self, ch: char) -> bool {
+        ch.len_utf8() <= self.extra_capacity()
+    }
+
+    /// Returns `true` if `additional` more bytes fit into the unused capacity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("Hi", 5);
+    /// assert!(s.reserve_exact_check(3));
+    /// assert!(!s.reserve_exact_check(4));
+    /// ```
+    #[inline]
+    pub const fn reserve_exact_check(&self, additional: usize) -> bool {
+        additional <= self.extra_capacity()
+    }
+
     /// Returns `true` if the string has zero length.
     ///
     /// # Example
@@ -322,6 +614,59 @@ impl<const CAP: usize> MicroStr<CAP>
         self.len == 0
     }
 
+    /// Returns `true` if the string contains at least one char.
+    ///
+    /// The negation of [`MicroStr::is_empty`], reads better in guard clauses.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// const HAS: bool = microstr!("a").has_content();
+    /// assert!(HAS);
+    /// assert!(!MicroStr::<4>::new().has_content());
+    /// ```
+    #[inline]
+    pub const fn has_content(&self) -> bool {
+        self.len != 0
+    }
+
+    /// Returns `true` if the string is empty or contains only whitespace.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// assert!(microstr!(" \t\n").is_blank());
+    /// assert!(!microstr!(" x ").is_blank());
+    /// ```
+    pub fn is_blank(&self) -> bool {
+        self.chars().all(char::is_whitespace)
+    }
+
+    /// Const version of [`MicroStr::is_blank`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// const BLANK: bool = microstr!(" \t\u{3000}").is_blank_const();
+    /// assert!(BLANK);
+    /// assert!(!microstr!(" x ").is_blank_const());
+    /// ```
+    pub const fn is_blank_const(&self) -> bool {
+        let mut i = 0;
+        while i < self.len {
+            // SAFETY: `i` always points at the start of a char
+            let c = unsafe { self.char_at_unchecked(i) };
+            if !is_whitespace_const(c) {
+                return false;
+            }
+            i += c.len_utf8();
+        }
+        true
+    }
+
     /// Returns the number of bytes currently used in the string.
     ///
     /// This is the length in bytes, not Unicode scalar values.
@@ -340,7 +685,7 @@ impl<const CAP: usize> MicroStr<CAP>
 
     /// Returns the number of Unicode scalar values (chars) in the string.
     ///
-    /// This is computed by iterating over `chars()`, so it's O(n).
+    /// Same as [`MicroStr::char_count`]: counts the char starts, so it's O(n).
     ///
     /// # Example
     ///
@@ -349,87 +694,301 @@ impl<const CAP: usize> MicroStr<CAP>
     /// let s = microstr!("💖Rust", 10);
     /// assert_eq!(s.len(), 5); // '💖' is one char, 'R','u','s','t'
     /// ```
-    pub fn len(&self) -> usize {
-        self.chars().count()
+    pub const fn len(&self) -> usize {
+        self.char_count()
     }
 
-    /* ##### PUSHERS ##### */
-
-    /// Appends a character to the end of the string without bounds checking.
-    ///
-    /// # Safety
+    /// Returns the number of Unicode scalar values (chars) in the string.
     ///
-    /// - The UTF-8 byte length of `ch` plus the current length of the string
-    ///   must be **less than or equal to** `CAP`. Otherwise, buffer overflow occurs.
+    /// Same as [`MicroStr::len`], but `const`: counts the bytes that start a char.
     ///
-    /// # Example (unsafe)
+    /// # Example
     ///
     /// ```rust
     /// use microstr::*;
-    /// let mut s: MicroStr<10> = MicroStr::new();
-    /// unsafe { s.push_unchecked('A') };
-    /// assert_eq!(s.as_str(), "A");
+    /// const COUNT: usize = microstr!("💖Rust").char_count();
+    /// assert_eq!(COUNT, 5);
     /// ```
-    pub const unsafe fn push_unchecked(&mut self, ch: char) {
-        let char_len = ch.len_utf8();
-        let char_bytes = char_to_bytes_utf8(ch);
-        let char_ptr = char_bytes.as_ptr();
-        let buf_ptr = self.as_mut_ptr().add(self.len);
-        ptr::copy_nonoverlapping(char_ptr, buf_ptr, char_len);
-        self.len += char_len;
+    pub const fn char_count(&self) -> usize {
+        let mut count = 0;
+        let mut i = 0;
+        while i < self.len {
+            if !is_utf8_continuation(self.buffer[i]) {
+                count += 1;
+            }
+            i += 1;
+        }
+        count
     }
 
-    /// Appends a character to the end of the string.
+    /// Returns `true` if all bytes of the string are ASCII.
     ///
-    /// # Parameters
+    /// Unlike [`str::is_ascii`] reached through `Deref`, this method is `const`.
     ///
-    /// - `ch`: The character to append.
+    /// # Example
     ///
-    /// # Returns
+    /// ```rust
+    /// use microstr::*;
+    /// const ASCII: bool = microstr!("Rust").is_ascii();
+    /// assert!(ASCII);
+    /// assert!(!microstr!("Раст").is_ascii());
+    /// ```
+    pub const fn is_ascii(&self) -> bool {
+        let mut i = 0;
+        while i < self.len {
+            if !self.buffer[i].is_ascii() {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Returns how many bytes the first `n` chars occupy.
     ///
-    /// - `Ok(())` if the character was successfully added.
-    /// - `Err(())` if there is insufficient space (including UTF-8 byte length).
+    /// Returns `bytes_len()` if `n` exceeds the number of chars.
     ///
     /// # Example
     ///
     /// ```rust
     /// use microstr::*;
-    /// let mut s = MicroStr::<1>::new();
-    /// assert!(s.push('A').is_ok());
-    /// assert!(s.push('B').is_err()); // No space
+    /// let s = microstr!("aж🦀");
+    /// assert_eq!(s.bytes_needed_for_chars(2), 3);
+    /// assert_eq!(s.bytes_needed_for_chars(10), 7);
     /// ```
-    pub const fn push(&mut self, ch: char) -> Result<(), ()> {
-        if ch.len_utf8() + self.len <= CAP {
-            // SAFETY: checked length
-            unsafe { self.push_unchecked(ch) };
-            return Ok(());
+    pub const fn bytes_needed_for_chars(&self, n: usize) -> usize {
+        let mut chars = 0;
+        let mut i = 0;
+        while i < self.len {
+            if !is_utf8_continuation(self.buffer[i]) {
+                if chars == n {
+                    return i;
+                }
+                chars += 1;
+            }
+            i += 1;
         }
-        Err(())
+        self.len
     }
-    
-    /// Appends a string slice without bounds checking.
+
+    /// Returns the byte at `idx`, or `None` if `idx` is past the content.
     ///
-    /// # Safety
+    /// A const, non-panicking alternative to indexing.
     ///
-    /// - The byte length of `s` plus the current length must be ≤ `CAP`.
-    /// - `s` must be valid UTF-8.
+    /// # Example
     ///
-    /// # Example (unsafe)
+    /// ```rust
+    /// use microstr::*;
+    /// const S: MicroStr<8> = microstr!("Hi", 8);
+    /// const H: Option<u8> = S.byte_at(0);
+    /// assert_eq!(H, Some(b'H'));
+    /// assert_eq!(S.byte_at(2), None);
+    /// ```
+    #[inline]
+    pub const fn byte_at(&self, idx: usize) -> Option<u8> {
+        if idx < self.len {
+            Some(self.buffer[idx])
+        }
+        else {
+            None
+        }
+    }
+
+    /// Returns the first byte of the content, or `None` if empty.
+    ///
+    /// # Example
     ///
     /// ```rust
-    /// use microstr::microstr;
-    /// let mut s = microstr!("", 5);
-    /// unsafe { s.push_str_unchecked("Hi") };
-    /// assert_eq!(s.as_str(), "Hi");
+    /// use microstr::*;
+    /// assert_eq!(microstr!("/path").first_byte(), Some(b'/'));
     /// ```
-    pub const unsafe fn push_str_unchecked(&mut self, s: &str) {
-        ptr::copy_nonoverlapping(s.as_ptr(), self.as_mut_ptr().add(self.len), s.len());
-        self.len += s.len();
+    #[inline]
+    pub const fn first_byte(&self) -> Option<u8> {
+        self.byte_at(0)
     }
 
-    /// Appends a string slice, truncating if necessary to fit capacity.
+    /// Returns the last byte of the content, or `None` if empty.
     ///
-    /// Ensures UTF-8 validity by not splitting multi-byte characters.
+    /// Handy for checking a trailing delimiter without decoding.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// assert_eq!(microstr!("line\n").last_byte(), Some(b'\n'));
+    /// ```
+    #[inline]
+    pub const fn last_byte(&self) -> Option<u8> {
+        match self.len.checked_sub(1) {
+            Some(idx) => Some(self.buffer[idx]),
+            None => None,
+        }
+    }
+
+    /// Returns the char starting at byte offset `byte_idx`.
+    ///
+    /// # Returns
+    ///
+    /// `Some(char)`, or `None` if `byte_idx` is past the content or not on a char boundary.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("aж🦀");
+    /// assert_eq!(s.char_at(1), Some('ж'));
+    /// assert_eq!(s.char_at(2), None); // inside 'ж'
+    /// assert_eq!(s.char_at(3), Some('🦀'));
+    /// ```
+    pub const fn char_at(&self, byte_idx: usize) -> Option<char> {
+        if byte_idx >= self.len || is_utf8_continuation(self.buffer[byte_idx]) {
+            return None;
+        }
+        // SAFETY: checked above, `byte_idx` is a char boundary inside the content
+        Some(unsafe { self.char_at_unchecked(byte_idx) })
+    }
+
+    /// Returns the char starting at byte offset `byte_idx`, without any checks.
+    ///
+    /// For hot loops that already track char offsets. See [`MicroStr::char_at`] for a safe version.
+    ///
+    /// # Safety
+    ///
+    /// `byte_idx` must be less than `bytes_len()` and lie on a char boundary.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("aж🦀");
+    /// assert_eq!(unsafe { s.char_at_unchecked(3) }, '🦀');
+    /// ```
+    pub const unsafe fn char_at_unchecked(&self, byte_idx: usize) -> char {
+        // SAFETY: the caller guarantees a char of valid UTF-8 starts at `byte_idx`,
+        // so all `width` bytes are in the content
+        unsafe {
+            let ptr = self.as_ptr().add(byte_idx);
+            let width = utf8_char_width(*ptr);
+            // Lead byte keeps 7, 5, 4 or 3 payload bits
+            let mut code = (*ptr & (0x7F >> (width - 1 + (width > 1) as usize))) as u32;
+            let mut i = 1;
+            while i < width {
+                code = code << 6 | (*ptr.add(i) & 0b0011_1111) as u32;
+                i += 1;
+            }
+            char::from_u32_unchecked(code)
+        }
+    }
+
+    /* ##### PUSHERS ##### */
+
+    /// Appends a character to the end of the string without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// - The UTF-8 byte length of `ch` plus the current length of the string
+    ///   must be **less than or equal to** `CAP`. Otherwise, buffer overflow occurs.
+    ///
+    /// # Example (unsafe)
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s: MicroStr<10> = MicroStr::new();
+    /// unsafe { s.push_unchecked('A') };
+    /// assert_eq!(s.as_str(), "A");
+    /// ```
+    pub const unsafe fn push_unchecked(&mut self, ch: char) {
+        let char_len = ch.len_utf8();
+        let char_bytes = char_to_bytes_utf8(ch);
+        let char_ptr = char_bytes.as_ptr();
+        let buf_ptr = self.as_mut_ptr().add(self.len);
+        ptr::copy_nonoverlapping(char_ptr, buf_ptr, char_len);
+        self.len += char_len;
+    }
+
+    /// Appends a character to the end of the string.
+    ///
+    /// # Parameters
+    ///
+    /// - `ch`: The character to append.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the character was successfully added.
+    /// - `Err(())` if there is insufficient space (including UTF-8 byte length).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = MicroStr::<1>::new();
+    /// assert!(s.push('A').is_ok());
+    /// assert!(s.push('B').is_err()); // No space
+    /// ```
+    #[allow(clippy::result_unit_err)]
+    pub const fn push(&mut self, ch: char) -> Result<(), ()> {
+        if self.can_push(ch) {
+            // SAFETY: checked length
+            unsafe { self.push_unchecked(ch) };
+            return Ok(());
+        }
+        Err(())
+    }
+
+    /// Appends the character `ch` repeated `count` times.
+    ///
+    /// Stops early if there is not enough space for the next character.
+    ///
+    /// # Returns
+    ///
+    /// The number of characters actually appended.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = MicroStr::<10>::new();
+    /// assert_eq!(s.append_char_n('=', 3), 3);
+    /// assert_eq!(s.append_char_n('🦀', 5), 1); // only 7 bytes left
+    /// assert_eq!(s.as_str(), "===🦀");
+    /// ```
+    pub const fn append_char_n(&mut self, ch: char, count: usize) -> usize {
+        let char_len = ch.len_utf8();
+        let fit = const_min(count, self.extra_capacity() / char_len);
+        let char_bytes = char_to_bytes_utf8(ch);
+        let mut i = 0;
+        while i < fit {
+            // SAFETY: `fit * char_len <= extra_capacity`, so every write stays in bounds
+            unsafe { ptr::copy_nonoverlapping(char_bytes.as_ptr(), self.as_mut_ptr().add(self.len), char_len) };
+            self.len += char_len;
+            i += 1;
+        }
+        fit
+    }
+
+    /// Appends a string slice without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// - The byte length of `s` plus the current length must be ≤ `CAP`.
+    /// - `s` must be valid UTF-8.
+    ///
+    /// # Example (unsafe)
+    ///
+    /// ```rust
+    /// use microstr::microstr;
+    /// let mut s = microstr!("", 5);
+    /// unsafe { s.push_str_unchecked("Hi") };
+    /// assert_eq!(s.as_str(), "Hi");
+    /// ```
+    pub const unsafe fn push_str_unchecked(&mut self, s: &str) {
+        ptr::copy_nonoverlapping(s.as_ptr(), self.as_mut_ptr().add(self.len), s.len());
+        self.len += s.len();
+    }
+
+    /// Appends a string slice, truncating if necessary to fit capacity.
+    ///
+    /// Ensures UTF-8 validity by not splitting multi-byte characters.
     ///
     /// # Parameters
     ///
@@ -458,153 +1017,1807 @@ impl<const CAP: usize> MicroStr<CAP>
         self.len += truncating_len;
         
         if truncating_len == s.len() {
-            return Ok(());
+            Ok(())
         }
         else {
-            return Err(truncating_len);
+            Err(truncating_len)
         }
     }
 
-    /* ##### TYPE CONVERTERS ##### */
+    /// Appends a string slice, truncating if necessary, and returns the bytes written.
+    ///
+    /// Same as [`MicroStr::push_str`], but always returns the number of appended bytes,
+    /// for loops that only care how far they got.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = MicroStr::<6>::new();
+    /// assert_eq!(s.write_str_counted("An"), 2);
+    /// assert_eq!(s.write_str_counted("河🌍"), 3);
+    /// assert_eq!(s.as_str(), "An河");
+    /// ```
+    pub const fn write_str_counted(&mut self, s: &str) -> usize {
+        match self.push_str(s) {
+            Ok(()) => s.len(),
+            Err(bytes) => bytes,
+        }
+    }
 
-    /// Returns a string slice of the current content.
+    /// Appends bytes from an iterator while they form valid UTF-8 and fit.
     ///
-    /// This slice is guaranteed to be valid UTF-8.
+    /// Multi-byte chars may be split across items. Stops at the first invalid byte, on overflow,
+    /// or if the iterator ends in the middle of a char; such an unfinished char is not appended.
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) - all bytes were appended
+    /// Err(usize) - only the first `n` bytes were appended
     ///
     /// # Example
     ///
     /// ```rust
     /// use microstr::*;
-    /// let s = microstr!("Hello", 10);
-    /// assert_eq!(s.as_str(), "Hello");
+    /// let mut s = MicroStr::<8>::new();
+    /// assert_eq!(s.push_bytes_iter("ёж".bytes()), Ok(()));
+    /// assert_eq!(s.push_bytes_iter([b'!', 0xFF, b'?']), Err(1));
+    /// assert_eq!(s.as_str(), "ёж!");
     /// ```
-    pub fn as_str(&self) -> &str {
-        // SAFETY: buffer always contains valid UTF-8
-        unsafe { from_utf8_unchecked(self.as_bytes()) }
+    pub fn push_bytes_iter<I: IntoIterator<Item = u8>>(&mut self, iter: I) -> Result<(), usize> {
+        let start = self.len;
+        let mut ch = [0u8; 4];
+        let mut pending = 0;
+        let mut width = 0;
+        for byte in iter {
+            if pending == 0 {
+                width = utf8_char_width(byte);
+            }
+            ch[pending] = byte;
+            pending += 1;
+            if pending < width {
+                continue;
+            }
+            pending = 0;
+            let Ok(ch) = from_utf8(&ch[..width]) else {
+                return Err(self.len - start);
+            };
+            if self.push_str(ch).is_err() {
+                return Err(self.len - start);
+            }
+        }
+        if pending == 0 {
+            Ok(())
+        }
+        else {
+            Err(self.len - start)
+        }
     }
 
-    /// Returns a mutable string slice of the current content.
+    /// Appends formatted text, truncating on overflow, and returns the bytes written.
     ///
-    /// Allows in-place mutation of the string, but you must ensure the result remains valid UTF-8.
+    /// Unlike `write!`, which only reports `fmt::Error`, tells how far the formatting got.
+    /// Formatting stops at the first piece that doesn't fit; the piece is truncated
+    /// without splitting multi-byte characters.
     ///
-    /// # Safety
+    /// # Returns
     ///
-    /// The caller must ensure that any modifications preserve UTF-8 validity.
+    /// Ok(usize) - everything fit, `n` bytes were appended
+    /// Err(usize) - only the first `n` bytes were appended due to capacity
     ///
     /// # Example
     ///
     /// ```rust
     /// use microstr::*;
-    /// let mut s = microstr!("Hello", 10);
-    /// let s_mut = s.as_str_mut();
-    /// s_mut.make_ascii_uppercase();
-    /// assert_eq!(s.as_str(), "HELLO");
+    /// let mut s = MicroStr::<8>::new();
+    /// let (x, y) = (12, -345);
+    /// assert_eq!(s.try_write_fmt(format_args!("{x},")), Ok(3));
+    /// assert_eq!(s.try_write_fmt(format_args!("{y},{y}")), Err(5));
+    /// assert_eq!(s.as_str(), "12,-345,");
     /// ```
-    pub fn as_str_mut(&mut self) -> &mut str {
-        // SAFETY: buffer always contains valid UTF-8
-        unsafe { from_utf8_unchecked_mut(self.as_mut_bytes()) }
+    pub fn try_write_fmt(&mut self, args: fmt::Arguments<'_>) -> Result<usize, usize> {
+        let start = self.len;
+        match self.write_fmt(args) {
+            Ok(()) => Ok(self.len - start),
+            Err(fmt::Error) => Err(self.len - start),
+        }
     }
 
-    /// Returns a byte slice of the current content.
+    /// Appends the `Display` representation of `value`, without a temporary string.
+    ///
+    /// Same as `write!(s, "{value}")`: on overflow the text is **truncated**
+    /// without splitting multi-byte characters.
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) - the whole value fit
+    /// Err(()) - the value was truncated due to capacity
     ///
     /// # Example
     ///
     /// ```rust
     /// use microstr::*;
-    /// let s = microstr!("Hi", 10);
-    /// assert_eq!(s.as_bytes(), b"Hi");
+    /// let mut s = MicroStr::<5>::new();
+    /// assert_eq!(s.append_display(42), Ok(()));
+    /// assert_eq!(s.append_display(-1.5), Err(()));
+    /// assert_eq!(s.as_str(), "42-1.");
     /// ```
-    #[inline]
-    pub fn as_bytes(&self) -> &[u8] {
-        &self.buffer[..self.len]
+    #[allow(clippy::result_unit_err)]
+    pub fn append_display<T: fmt::Display>(&mut self, value: T) -> Result<(), ()> {
+        write!(self, "{value}").map_err(|_| ())
     }
 
-    /// Returns a mutable byte slice of the current content.
+    /// Appends a string slice followed by `'\n'`.
+    ///
+    /// The line is truncated like in [`MicroStr::push_str`]. The newline is appended only
+    /// if the whole line fits, and it is dropped if there is no room left for it.
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) - the line and the newline fit
+    /// Err(usize) - only the first `n` bytes of the line were appended, without newline
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = MicroStr::<8>::new();
+    /// assert_eq!(s.push_line("one"), Ok(()));
+    /// assert_eq!(s.push_line("two"), Ok(()));
+    /// assert_eq!(s.push_line("3"), Err(0)); // newline doesn't fit
+    /// assert_eq!(s.as_str(), "one\ntwo\n");
+    /// ```
+    pub const fn push_line(&mut self, s: &str) -> Result<(), usize> {
+        if s.len() < self.extra_capacity() {
+            // SAFETY: checked length, line and newline fit
+            unsafe {
+                self.push_str_unchecked(s);
+                self.push_unchecked('\n');
+            }
+            return Ok(());
+        }
+        match self.push_str(s) {
+            Ok(()) => Err(s.len()),
+            Err(bytes) => Err(bytes),
+        }
+    }
+
+    /// Appends the chars of `a` and `b` alternately, separated by `sep`.
+    ///
+    /// When one string runs out, the rest of the other follows, still separated.
+    /// Stops at the first char that doesn't fit.
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) - everything fit
+    /// Err(usize) - only the first `n` bytes were appended due to capacity
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = MicroStr::<16>::new();
+    /// assert_eq!(s.push_interleaved("abc", "12", '|'), Ok(()));
+    /// assert_eq!(s.as_str(), "a|1|b|2|c");
+    /// ```
+    pub fn push_interleaved(&mut self, a: &str, b: &str, sep: char) -> Result<(), usize> {
+        let start = self.len;
+        let (mut a, mut b) = (a.chars(), b.chars());
+        let mut from_a = true;
+        let mut sep_len = 0;
+        loop {
+            let next = if from_a { a.next().or_else(|| b.next()) } else { b.next().or_else(|| a.next()) };
+            let Some(ch) = next else {
+                return Ok(());
+            };
+            if !self.reserve_exact_check(sep_len + ch.len_utf8()) {
+                return Err(self.len - start);
+            }
+            // SAFETY: checked length, separator and char fit
+            unsafe {
+                if sep_len != 0 {
+                    self.push_unchecked(sep);
+                }
+                self.push_unchecked(ch);
+            }
+            sep_len = sep.len_utf8();
+            from_a = !from_a;
+        }
+    }
+
+    /// Inserts a string slice at the front, shifting the existing content right.
+    ///
+    /// `s` takes priority: if there is not enough space, the **existing content is truncated**
+    /// from the end to make room (never splitting multi-byte characters).
+    /// If `s` itself doesn't fit, it is truncated as well and the existing content is dropped.
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) - `s` and all existing content were kept
+    /// Err(usize) - only the first `n` bytes of `s` followed by the existing content were kept,
+    /// so `n` is the new length: if `n < s.len()`, `s` itself was truncated
+    /// and nothing of the existing content is left
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("world", 10);
+    /// assert_eq!(s.prepend("Hello "), Err(10));
+    /// assert_eq!(s.as_str(), "Hello worl");
+    ///
+    /// let mut s = microstr!("abc", 5);
+    /// assert_eq!(s.prepend("Привет"), Err(4)); // 'и' doesn't fit
+    /// assert_eq!(s.as_str(), "Пр");
+    /// ```
+    pub fn prepend(&mut self, s: &str) -> Result<(), usize> {
+        let prefix_len = utf8_truncator(s, CAP);
+        let keep = if prefix_len < s.len() {
+            0
+        }
+        else {
+            utf8_floor(&self.buffer, const_min(self.len, CAP - prefix_len))
+        };
+        self.buffer.copy_within(0..keep, prefix_len);
+        self.buffer[..prefix_len].copy_from_slice(&s.as_bytes()[..prefix_len]);
+        let old_len = self.len;
+        self.len = prefix_len + keep;
+        if prefix_len == s.len() && keep == old_len {
+            Ok(())
+        }
+        else {
+            Err(self.len)
+        }
+    }
+
+    /// Inserts a string slice before the char at `char_idx`, only if it fits entirely.
+    ///
+    /// `char_idx` may be equal to the number of chars, to insert at the end.
+    /// On failure, the string is left unchanged.
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) - `s` was inserted
+    /// Err(()) - `char_idx` is out of range, or `s` doesn't fit
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("ключ: 1", 16);
+    /// assert_eq!(s.try_insert_str(4, "и"), Ok(()));
+    /// assert_eq!(s.as_str(), "ключи: 1");
+    /// assert_eq!(s.try_insert_str(0, "слишком длинно"), Err(()));
+    /// assert_eq!(s.as_str(), "ключи: 1");
+    /// ```
+    #[allow(clippy::result_unit_err)]
+    pub fn try_insert_str(&mut self, char_idx: usize, s: &str) -> Result<(), ()> {
+        let byte_idx = self.char_boundaries().nth(char_idx).ok_or(())?;
+        if !self.reserve_exact_check(s.len()) {
+            return Err(());
+        }
+        self.buffer.copy_within(byte_idx..self.len, byte_idx + s.len());
+        self.buffer[byte_idx..byte_idx + s.len()].copy_from_slice(s.as_bytes());
+        self.len += s.len();
+        Ok(())
+    }
+
+    /* ##### TYPE CONVERTERS ##### */
+
+    /// Returns a string slice of the current content.
+    ///
+    /// This slice is guaranteed to be valid UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("Hello", 10);
+    /// assert_eq!(s.as_str(), "Hello");
+    /// ```
+    pub const fn as_str(&self) -> &str {
+        // SAFETY: buffer always contains valid UTF-8
+        unsafe { from_utf8_unchecked(self.as_bytes()) }
+    }
+
+    /// Returns a string slice of the current content, validating UTF-8.
+    ///
+    /// Unlike [`MicroStr::as_str`], this actually checks the content, which helps
+    /// to debug unsafe code (FFI, `as_mut_ptr` writes) that may break the UTF-8 invariant.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("Hi");
+    /// assert_eq!(s.as_str_checked(), Ok("Hi"));
+    /// unsafe { *s.as_mut_ptr() = 0xff };
+    /// assert!(s.as_str_checked().is_err());
+    /// ```
+    pub fn as_str_checked(&self) -> Result<&str, Utf8Error> {
+        from_utf8(self.as_bytes())
+    }
+
+    /// Returns the content as parser input.
+    ///
+    /// Same as [`MicroStr::as_str`], named for passing a `MicroStr` to parser
+    /// combinators and other functions taking `&str`. `&*s` works as well thanks to `Deref`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// fn parse_digits(input: &str) -> (&str, &str) {
+    ///     let end = input.find(|c: char| !c.is_ascii_digit()).unwrap_or(input.len());
+    ///     input.split_at(end)
+    /// }
+    ///
+    /// let s = microstr!("42px");
+    /// assert_eq!(parse_digits(s.as_input()), ("42", "px"));
+    /// assert_eq!(parse_digits(&s), ("42", "px"));
+    /// ```
+    #[inline]
+    pub fn as_input(&self) -> &str {
+        self.as_str()
+    }
+
+    /// Parses the content into another type, like [`str::parse`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// assert_eq!(microstr!("42").parse::<u32>(), Ok(42));
+    /// assert!(microstr!("4x2").parse::<u32>().is_err());
+    /// ```
+    #[inline]
+    pub fn parse<T: FromStr>(&self) -> Result<T, T::Err> {
+        self.as_str().parse()
+    }
+
+    /// Parses the content as a decimal `u64`.
+    ///
+    /// A minimal `const` parser: accepts ASCII digits only, without sign or whitespace.
+    ///
+    /// # Returns
+    ///
+    /// `Some(u64)`, or `None` if the content is empty, has a non-digit char or overflows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// const PORT: Option<u64> = microstr!("8080").parse_u64();
+    /// assert_eq!(PORT, Some(8080));
+    /// assert_eq!(microstr!("-1").parse_u64(), None);
+    /// ```
+    pub const fn parse_u64(&self) -> Option<u64> {
+        if self.len == 0 {
+            return None;
+        }
+        let mut result: u64 = 0;
+        let mut i = 0;
+        while i < self.len {
+            let byte = self.buffer[i];
+            if !byte.is_ascii_digit() {
+                return None;
+            }
+            result = match result.checked_mul(10) {
+                Some(value) => match value.checked_add((byte - b'0') as u64) {
+                    Some(value) => value,
+                    None => return None,
+                },
+                None => return None,
+            };
+            i += 1;
+        }
+        Some(result)
+    }
+
+    /// Returns a mutable string slice of the current content.
+    ///
+    /// Allows in-place mutation of the string, but you must ensure the result remains valid UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that any modifications preserve UTF-8 validity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("Hello", 10);
+    /// let s_mut = s.as_str_mut();
+    /// s_mut.make_ascii_uppercase();
+    /// assert_eq!(s.as_str(), "HELLO");
+    /// ```
+    pub const fn as_str_mut(&mut self) -> &mut str {
+        // SAFETY: buffer always contains valid UTF-8
+        unsafe { from_utf8_unchecked_mut(self.as_mut_bytes()) }
+    }
+
+    /// Returns a byte slice of the current content.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("Hi", 10);
+    /// assert_eq!(s.as_bytes(), b"Hi");
+    /// ```
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.buffer.split_at(self.len).0
+    }
+
+    /// Returns a mutable byte slice of the current content.
+    ///
+    /// You must ensure that any modifications result in valid UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("abc", 10);
+    /// let bytes = s.as_mut_bytes();
+    /// bytes[0] = b'x';
+    /// assert_eq!(s.as_str(), "xbc");
+    /// ```
+    pub const fn as_mut_bytes(&mut self) -> &mut [u8] {
+        self.buffer.split_at_mut(self.len).0
+    }
+
+    /// Returns the whole mutable backing array, including the unused capacity.
+    ///
+    /// Intended for FFI writes. The length is not updated automatically,
+    /// correct it afterwards with [`MicroStr::set_len`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the first `bytes_len()` bytes remain valid UTF-8
+    /// until the length is corrected, and that the corrected content is valid UTF-8.
+    ///
+    /// # Example (unsafe)
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = MicroStr::<8>::new();
+    /// unsafe {
+    ///     let array = s.as_mut_array();
+    ///     array[..4].copy_from_slice(b"FFI!");
+    ///     s.set_len(4);
+    /// }
+    /// assert_eq!(s.as_str(), "FFI!");
+    /// ```
+    #[inline]
+    pub const unsafe fn as_mut_array(&mut self) -> &mut [u8; CAP] {
+        &mut self.buffer
+    }
+
+    /// Consumes the `MicroStr` and returns the raw byte buffer.
+    ///
+    /// The buffer is exactly `CAP` bytes long. Unused bytes are unspecified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("Hi", 8);
+    /// let buf = s.into_raw_buffer();
+    /// assert_eq!(&buf[..2], b"Hi");
+    /// ```
+    pub const fn into_raw_buffer(self) -> [u8; CAP] {
+        self.buffer
+    }
+
+    /// Encodes the content as UTF-16 into a caller-provided buffer.
+    ///
+    /// Stops when `out` is full; surrogate pairs are never split.
+    ///
+    /// # Returns
+    ///
+    /// The number of `u16` units written.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("Hi🦀");
+    /// let mut out = [0u16; 8];
+    /// assert_eq!(s.encode_utf16_into(&mut out), 4);
+    /// assert_eq!(out[..4], [0x0048, 0x0069, 0xD83E, 0xDD80]);
+    /// ```
+    pub fn encode_utf16_into(&self, out: &mut [u16]) -> usize {
+        let mut written = 0;
+        for ch in self.chars() {
+            let ch_len = ch.len_utf16();
+            if written + ch_len > out.len() {
+                break;
+            }
+            ch.encode_utf16(&mut out[written..]);
+            written += ch_len;
+        }
+        written
+    }
+
+    /// Copies the content bytes into `out`, truncating if it is too small.
+    ///
+    /// Truncation never splits multi-byte characters, so `out[..n]` is valid UTF-8.
+    /// Bytes of `out` past the returned length are left untouched.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("ёж");
+    /// let mut frame = [0u8; 3];
+    /// assert_eq!(s.copy_to_slice(&mut frame), 2);
+    /// assert_eq!(&frame[..2], "ё".as_bytes());
+    /// ```
+    pub const fn copy_to_slice(&self, out: &mut [u8]) -> usize {
+        let count = utf8_floor(&self.buffer, const_min(self.len, out.len()));
+        // SAFETY: `count` is within both the content and `out`, and they don't overlap
+        unsafe { ptr::copy_nonoverlapping(self.as_ptr(), out.as_mut_ptr(), count) };
+        count
+    }
+
+    /// Copies the content into an existing `MicroStr` of any capacity, replacing its content.
+    ///
+    /// If `dst` is smaller than the content, the copy is **truncated** without splitting
+    /// multi-byte characters. Handy for reusing one scratch buffer across iterations.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("Привет");
+    /// let mut dst = microstr!("old", 5);
+    /// s.clone_into_cap(&mut dst);
+    /// assert_eq!(dst.as_str(), "Пр");
+    /// ```
+    pub fn clone_into_cap<const B: usize>(&self, dst: &mut MicroStr<B>) {
+        dst.clear();
+        let _ = dst.push_str(self.as_str());
+    }
+
+    /// Returns a value that displays at most `max_chars` characters of the string,
+    /// followed by `'…'` if the content was cut.
+    ///
+    /// Useful for clamping UI and log lines without allocation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("Hello, world");
+    /// assert_eq!(format!("{}", s.display_truncated(5)), "Hello…");
+    /// assert_eq!(format!("{}", s.display_truncated(20)), "Hello, world");
+    /// ```
+    pub fn display_truncated(&self, max_chars: usize) -> impl fmt::Display + '_ {
+        TruncatedDisplay { s: self.as_str(), max_chars }
+    }
+
+    /* ##### COMPARISON ##### */
+
+    /// Compares content with another `MicroStr` of the same capacity.
+    ///
+    /// Compares the content bytes directly, a fast path for hot loops.
+    /// `==` works across capacities as well.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let a = microstr!("token", 8);
+    /// assert!(a.eq_bytes(&microstr!("token", 8)));
+    /// assert!(!a.eq_bytes(&microstr!("tokens", 8)));
+    /// ```
+    #[inline]
+    pub fn eq_bytes(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+
+    /// Compares content with `other`, ignoring ASCII case.
+    ///
+    /// Compares bytes with ASCII letters folded to lowercase, so sorting doesn't need
+    /// lowercase copies. Non-ASCII chars are compared as is.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// use core::cmp::Ordering;
+    /// let s = microstr!("apple");
+    /// assert_eq!(s.cmp_ignore_ascii_case("APPLE"), Ordering::Equal);
+    /// assert_eq!(s.cmp_ignore_ascii_case("Banana"), Ordering::Less);
+    /// ```
+    pub fn cmp_ignore_ascii_case(&self, other: &str) -> Ordering {
+        let lhs = self.as_bytes().iter().map(u8::to_ascii_lowercase);
+        let rhs = other.as_bytes().iter().map(u8::to_ascii_lowercase);
+        lhs.cmp(rhs)
+    }
+
+    /// Compares by byte length first, then lexicographically.
+    ///
+    /// Unlike the `Ord` impl, shorter strings always go first.
+    /// Useful for length-bucketed structures.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// use core::cmp::Ordering;
+    /// let a = microstr!("zz", 4);
+    /// let b = microstr!("aaa", 4);
+    /// assert_eq!(a.cmp_by_len(&b), Ordering::Less);
+    /// assert_eq!(a.cmp(&b), Ordering::Greater);
+    /// ```
+    pub fn cmp_by_len(&self, other: &MicroStr<CAP>) -> Ordering {
+        self.len.cmp(&other.len).then_with(|| self.as_bytes().cmp(other.as_bytes()))
+    }
+
+    /* ##### SEARCHING ##### */
+
+    /// Returns `true` if the string contains `needle`.
+    ///
+    /// Unlike [`str::contains`] reached through `Deref`, this method is `const`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// const FOUND: bool = microstr!("Hello, world").contains_str("o, w");
+    /// assert!(FOUND);
+    /// ```
+    pub const fn contains_str(&self, needle: &str) -> bool {
+        find_bytes(self.as_bytes(), needle.as_bytes()).is_some()
+    }
+
+    /// Returns `true` if the content starts with the raw bytes `prefix`.
+    ///
+    /// Does not decode UTF-8, so it is usable with binary protocol markers and in const context.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("\x02STX frame");
+    /// assert!(s.starts_with_bytes(&[0x02]));
+    /// assert!(!s.starts_with_bytes(b"frame"));
+    /// ```
+    pub const fn starts_with_bytes(&self, prefix: &[u8]) -> bool {
+        prefix.len() <= self.len && bytes_eq_at(&self.buffer, 0, prefix)
+    }
+
+    /// Returns `true` if the content ends with the raw bytes `suffix`.
+    ///
+    /// Does not decode UTF-8, so it is usable with binary protocol markers and in const context.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("frame\r\n");
+    /// assert!(s.ends_with_bytes(b"\r\n"));
+    /// ```
+    pub const fn ends_with_bytes(&self, suffix: &[u8]) -> bool {
+        suffix.len() <= self.len && bytes_eq_at(&self.buffer, self.len - suffix.len(), suffix)
+    }
+
+    /// Returns the number of chars matching `f`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("education");
+    /// assert_eq!(s.count_chars_if(|c| "aeiou".contains(c)), 5);
+    /// ```
+    pub fn count_chars_if<F: Fn(char) -> bool>(&self, f: F) -> usize {
+        self.chars().filter(|&ch| f(ch)).count()
+    }
+
+    /// Returns `true` if every char matches `f`, or the string is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// assert!(microstr!("12345").all_chars(|c| c.is_ascii_digit()));
+    /// assert!(!microstr!("12a45").all_chars(|c| c.is_ascii_digit()));
+    /// ```
+    pub fn all_chars<F: Fn(char) -> bool>(&self, f: F) -> bool {
+        self.chars().all(f)
+    }
+
+    /// Returns `true` if at least one char matches `f`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// assert!(microstr!("pass word").any_char(char::is_whitespace));
+    /// assert!(!microstr!("").any_char(char::is_whitespace));
+    /// ```
+    pub fn any_char<F: Fn(char) -> bool>(&self, f: F) -> bool {
+        self.chars().any(f)
+    }
+
+    /// Returns `true` if all chars are ASCII letters or digits, or the string is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// assert!(microstr!("user42").is_alphanumeric_ascii());
+    /// assert!(!microstr!("user_42").is_alphanumeric_ascii());
+    /// ```
+    pub fn is_alphanumeric_ascii(&self) -> bool {
+        self.all_chars(|ch| ch.is_ascii_alphanumeric())
+    }
+
+    /// Returns the char indices of the occurrences of `needle`, in a fixed array.
+    ///
+    /// Only the first `N` positions are stored; unfilled elements are `0`.
+    ///
+    /// # Returns
+    ///
+    /// The array of positions and the total number of occurrences, which may exceed `N`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("a_b_c_d");
+    /// let (positions, count) = s.match_positions::<2>('_');
+    /// assert_eq!(positions, [1, 3]);
+    /// assert_eq!(count, 3);
+    /// ```
+    pub fn match_positions<const N: usize>(&self, needle: char) -> ([usize; N], usize) {
+        let mut positions = [0; N];
+        let mut count = 0;
+        for (char_idx, _) in self.chars().enumerate().filter(|&(_, ch)| ch == needle) {
+            if let Some(slot) = positions.get_mut(count) {
+                *slot = char_idx;
+            }
+            count += 1;
+        }
+        (positions, count)
+    }
+
+    /// Returns the number of occurrences of `ch`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// assert_eq!(microstr!("a,b,,c").count_char_matches(','), 3);
+    /// ```
+    pub fn count_char_matches(&self, ch: char) -> usize {
+        let ch_bytes = char_to_bytes_utf8(ch);
+        self.count_occurrences(&ch_bytes[..ch.len_utf8()])
+    }
+
+    /// Returns the number of non-overlapping occurrences of `needle`, like `str::matches`.
+    ///
+    /// An empty `needle` matches at every char boundary.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// assert_eq!(microstr!("aaaa").count_str_matches("aa"), 2);
+    /// ```
+    pub fn count_str_matches(&self, needle: &str) -> usize {
+        self.matches(needle).count()
+    }
+
+    /// Returns the number of lines, same as `lines().count()`.
+    ///
+    /// A final `'\n'` doesn't start a new empty line. Useful for sizing display buffers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// assert_eq!(microstr!("one\ntwo").line_count(), 2);
+    /// assert_eq!(microstr!("one\ntwo\n").line_count(), 2);
+    /// ```
+    pub const fn line_count(&self) -> usize {
+        let mut count = 0;
+        let mut i = 0;
+        while i < self.len {
+            if self.buffer[i] == b'\n' {
+                count += 1;
+            }
+            i += 1;
+        }
+        if self.len > 0 && self.buffer[self.len - 1] != b'\n' {
+            count += 1;
+        }
+        count
+    }
+
+    /// Returns the char index of the `n`-th (counting from 0) char matching `f`.
+    ///
+    /// # Returns
+    ///
+    /// `Some(char_idx)`, or `None` if fewer than `n + 1` chars match.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("ab1cd2");
+    /// assert_eq!(s.position_of_nth(1, |c| c.is_ascii_digit()), Some(5));
+    /// assert_eq!(s.position_of_nth(2, |c| c.is_ascii_digit()), None);
+    /// ```
+    pub fn position_of_nth<F: Fn(char) -> bool>(&self, n: usize, f: F) -> Option<usize> {
+        self.chars()
+            .enumerate()
+            .filter(|&(_, ch)| f(ch))
+            .nth(n)
+            .map(|(idx, _)| idx)
+    }
+
+    /// Returns the char index of the first char for which `pred` is `false`.
+    ///
+    /// Assumes the content is partitioned by `pred`: all matching chars come first.
+    /// Chars are not randomly accessible, so this is O(n) like [`MicroStr::len`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("0042abc");
+    /// assert_eq!(s.partition_point(|c| c.is_ascii_digit()), 4);
+    /// ```
+    pub fn partition_point<F: Fn(char) -> bool>(&self, pred: F) -> usize {
+        self.chars().take_while(|&ch| pred(ch)).count()
+    }
+
+    /// Returns an iterator over the byte offsets of all char boundaries.
+    ///
+    /// Yields the start of each char followed by `bytes_len()`, so `0` and the end are both included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("aж🦀");
+    /// assert!(s.char_boundaries().eq([0, 1, 3, 7]));
+    /// ```
+    pub fn char_boundaries(&self) -> impl Iterator<Item = usize> + '_ {
+        self.char_indices()
+            .map(|(idx, _)| idx)
+            .chain(core::iter::once(self.len))
+    }
+
+    /* ##### SPLITTERS ##### */
+
+    /// Splits the string at a byte offset.
+    ///
+    /// Unlike [`str::split_at`], doesn't panic on a bad offset.
+    ///
+    /// # Returns
+    ///
+    /// `Some((before, after))`, or `None` if `byte_idx` is past the end or not on a char boundary.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("ёж");
+    /// assert_eq!(s.split_at_byte(2), Some(("ё", "ж")));
+    /// assert_eq!(s.split_at_byte(1), None);
+    /// ```
+    pub fn split_at_byte(&self, byte_idx: usize) -> Option<(&str, &str)> {
+        self.as_str().split_at_checked(byte_idx)
+    }
+
+    /// Splits the string on the first occurrence of `delim`.
+    ///
+    /// Scans the raw bytes for the UTF-8 encoding of `delim`, which is faster than
+    /// the generic pattern search of [`str::split_once`].
+    ///
+    /// # Returns
+    ///
+    /// `Some((before, after))` without the delimiter, or `None` if `delim` is not found.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("key=value=extra");
+    /// assert_eq!(s.split_once_char('='), Some(("key", "value=extra")));
+    /// assert_eq!(s.split_once_char(':'), None);
+    /// ```
+    pub fn split_once_char(&self, delim: char) -> Option<(&str, &str)> {
+        let delim_bytes = char_to_bytes_utf8(delim);
+        let delim_len = delim.len_utf8();
+        let idx = find_bytes(self.as_bytes(), &delim_bytes[..delim_len])?;
+        let s = self.as_str();
+        Some((&s[..idx], &s[idx + delim_len..]))
+    }
+
+    /// Splits the string on the last occurrence of `delim`.
+    ///
+    /// Reverse counterpart of [`MicroStr::split_once_char`].
+    ///
+    /// # Returns
+    ///
+    /// `Some((before, after))` without the delimiter, or `None` if `delim` is not found.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("key=value=extra");
+    /// assert_eq!(s.rsplit_once_char('='), Some(("key=value", "extra")));
+    /// ```
+    pub fn rsplit_once_char(&self, delim: char) -> Option<(&str, &str)> {
+        let delim_bytes = char_to_bytes_utf8(delim);
+        let delim_len = delim.len_utf8();
+        let idx = rfind_bytes(self.as_bytes(), &delim_bytes[..delim_len])?;
+        let s = self.as_str();
+        Some((&s[..idx], &s[idx + delim_len..]))
+    }
+
+    /// Splits off the last char.
+    ///
+    /// # Returns
+    ///
+    /// `Some((rest, last))`, or `None` if the string is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("10€");
+    /// assert_eq!(s.split_last_char(), Some(("10", '€')));
+    /// ```
+    pub fn split_last_char(&self) -> Option<(&str, char)> {
+        if self.len == 0 {
+            return None;
+        }
+        let mut start = self.len - 1;
+        while is_utf8_continuation(self.buffer[start]) {
+            start -= 1;
+        }
+        // SAFETY: `start` is the boundary of the last char
+        let last = unsafe { self.char_at_unchecked(start) };
+        Some((&self.as_str()[..start], last))
+    }
+
+    /// Splits the string by `delim` into a fixed array, like [`str::split_terminator`].
+    ///
+    /// A trailing delimiter doesn't produce an empty last segment.
+    /// Only the first `N` segments are stored, the rest are ignored.
+    ///
+    /// # Returns
+    ///
+    /// The array of segments and the number of filled segments.
+    /// Unfilled elements are empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("a;b;");
+    /// let (parts, count) = s.split_terminator_into::<4>(';');
+    /// assert_eq!(count, 2);
+    /// assert_eq!(parts[0].as_str(), "a");
+    /// assert_eq!(parts[1].as_str(), "b");
+    /// ```
+    pub fn split_terminator_into<const N: usize>(&self, delim: char) -> ([Self; N], usize) {
+        let mut result = [const { Self::new() }; N];
+        let mut count = 0;
+        for (slot, part) in result.iter_mut().zip(self.split_terminator(delim)) {
+            // SAFETY: `part` is a substring of `self`, so it fits
+            *slot = unsafe { Self::from_str_unchecked(part) };
+            count += 1;
+        }
+        (result, count)
+    }
+
+    /// Splits the string by `delim` from the right into a fixed array, like [`str::rsplit`].
+    ///
+    /// Segments are stored in reverse order, the last segment first.
+    /// Only the first `N` segments are stored, the rest are ignored.
+    ///
+    /// # Returns
+    ///
+    /// The array of segments and the number of filled segments.
+    /// Unfilled elements are empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("a.b.c");
+    /// let (parts, count) = s.rsplit_into::<2>('.');
+    /// assert_eq!(count, 2);
+    /// assert_eq!(parts[0].as_str(), "c");
+    /// assert_eq!(parts[1].as_str(), "b");
+    /// ```
+    pub fn rsplit_into<const N: usize>(&self, delim: char) -> ([Self; N], usize) {
+        let mut result = [const { Self::new() }; N];
+        let mut count = 0;
+        for (slot, part) in result.iter_mut().zip(self.rsplit(delim)) {
+            // SAFETY: `part` is a substring of `self`, so it fits
+            *slot = unsafe { Self::from_str_unchecked(part) };
+            count += 1;
+        }
+        (result, count)
+    }
+
+    /* ##### TRANSFORMERS ##### */
+
+    /// Returns a new `MicroStr` with every char mapped by `f`.
+    ///
+    /// If the mapped chars are wider and overflow the capacity, the result is **truncated**
+    /// at the last char that fits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("abc", 5);
+    /// assert_eq!(s.map_chars(|c| c.to_ascii_uppercase()).as_str(), "ABC");
+    /// assert_eq!(s.map_chars(|_| 'ж').as_str(), "жж"); // Truncated
+    /// ```
+    pub fn map_chars<F: Fn(char) -> char>(&self, f: F) -> Self {
+        let mut result = Self::new();
+        for ch in self.chars().map(f) {
+            if result.push(ch).is_err() {
+                break;
+            }
+        }
+        result
+    }
+
+    /// Returns a new `MicroStr` containing only the chars matching `f`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("a1b2c3");
+    /// assert_eq!(s.filter_chars(|c| c.is_alphabetic()).as_str(), "abc");
+    /// ```
+    pub fn filter_chars<F: Fn(char) -> bool>(&self, f: F) -> Self {
+        let mut result = Self::new();
+        for ch in self.chars().filter(|&ch| f(ch)) {
+            // SAFETY: filtered content is never longer than `self`
+            unsafe { result.push_unchecked(ch) };
+        }
+        result
+    }
+
+    /* ##### MODIFICATORS ##### */
+
+    /// Clears str to `default` state.
+    /// 
+    /// Sets length as 0 and first byte b'\0'
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("Clear me!");
+    /// s.clear();
+    /// assert_eq!(s.as_str(), "");
+    /// ```
+    #[inline]
+    pub const fn clear(&mut self) {
+        self.len = 0;
+        if CAP > 0 {
+            self.buffer[0] = b'\0';
+        }
+    }
+
+    /// Takes the content out, leaving `self` empty.
+    ///
+    /// Like [`core::mem::take`], but usable in const context.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut buf = microstr!("packet", 16);
+    /// let packet = buf.take();
+    /// assert_eq!(packet.as_str(), "packet");
+    /// assert!(buf.is_empty());
+    /// ```
+    #[inline]
+    pub const fn take(&mut self) -> Self {
+        let taken = Self { buffer: self.buffer, len: self.len };
+        self.clear();
+        taken
+    }
+
+    /// Sets the length of the content in bytes.
+    ///
+    /// Useful after writing directly into the buffer via [`MicroStr::as_mut_ptr`].
+    ///
+    /// # Safety
+    ///
+    /// - `len` must be less than or equal to `CAP`.
+    /// - The first `len` bytes of the buffer must be valid UTF-8 before any method
+    ///   other than [`MicroStr::fix_char_boundary`] is called. The only allowed defect
+    ///   is an incomplete last char, which `fix_char_boundary` then drops.
+    ///
+    /// # Example (unsafe)
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = MicroStr::<8>::new();
+    /// unsafe {
+    ///     s.as_mut_ptr().copy_from_nonoverlapping(b"FFI".as_ptr(), 3);
+    ///     s.set_len(3);
+    /// }
+    /// assert_eq!(s.as_str(), "FFI");
+    /// ```
+    #[inline]
+    pub const unsafe fn set_len(&mut self, len: usize) {
+        self.len = len;
+    }
+
+    /// Copies `count` bytes of the buffer from offset `from` to offset `to`.
+    ///
+    /// The ranges may overlap. A low-level primitive for custom edits
+    /// like inserting or removing, the length is not changed.
+    ///
+    /// # Safety
+    ///
+    /// - `from + count` and `to + count` must be less than or equal to `CAP`.
+    /// - The caller must ensure the content is valid UTF-8 afterwards,
+    ///   correcting the length with [`MicroStr::set_len`] if needed.
+    ///
+    /// # Example (unsafe)
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("world", 8);
+    /// unsafe {
+    ///     s.move_bytes(0, 2, 5); // make room at the front
+    ///     s.as_mut_ptr().copy_from_nonoverlapping(b"a ".as_ptr(), 2);
+    ///     s.set_len(7);
+    /// }
+    /// assert_eq!(s.as_str(), "a world");
+    /// ```
+    #[inline]
+    pub const unsafe fn move_bytes(&mut self, from: usize, to: usize, count: usize) {
+        // Both ends come from one mutable pointer: a separate shared borrow for the source
+        // would be invalidated by the mutable one (Stacked Borrows)
+        let ptr = self.as_mut_ptr();
+        ptr::copy(ptr.add(from), ptr.add(to), count);
+    }
+
+    /// Backs the length up to the nearest char boundary.
+    ///
+    /// A recovery tool for FFI write patterns where the length may land in the middle
+    /// of a multi-byte char: the incomplete char at the end is dropped.
+    ///
+    /// # Safety
+    ///
+    /// Apart from the possibly incomplete last char, the first `bytes_len()` bytes
+    /// of the buffer must be valid UTF-8.
+    ///
+    /// # Example (unsafe)
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("Кот");
+    /// unsafe {
+    ///     s.set_len(5); // in the middle of 'т'
+    ///     s.fix_char_boundary();
+    /// }
+    /// assert_eq!(s.as_str(), "Ко");
+    /// ```
+    pub const unsafe fn fix_char_boundary(&mut self) {
+        self.len = utf8_floor(&self.buffer, self.len);
+    }
+
+    /// Truncates the string by index of **char**.
+    ///
+    /// If `char_idx` is greater than or equal to the number of characters,
+    /// this is a no-op.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("💖Rust", 10);
+    /// s.truncate(1);
+    /// assert_eq!(s.as_str(), "💖");
+    /// ```
+    pub fn truncate(&mut self, char_idx : usize) {
+        if char_idx >= self.len() { return; }
+        let mut byte_idx = 0;
+        for (idx, ch) in self.chars().enumerate() {
+            if idx == char_idx {
+                break;
+            }
+            byte_idx += ch.len_utf8();
+        }
+        // SAFETY:
+        // - `byte_idx` is computed by summing `ch.len_utf8()` for valid UTF-8 characters.
+        // - The loop stops when `idx == char_idx`, so `byte_idx` corresponds to the start of the next char.
+        // - Since `char_idx < self.len()`, we know `byte_idx < self.len() <= CAP`.
+        // - `self.as_mut_ptr()` is valid for `CAP` bytes.
+        // - `byte_idx < CAP`, so `self.as_mut_ptr().add(byte_idx)` is in bounds.
+        // - We write `0` (null terminator) — safe for UTF-8 and FFI.
+        unsafe { self.as_mut_ptr().add(byte_idx).write(0) };
+        self.len = byte_idx;
+    }
+
+    /// Overwrites the chars starting at `char_idx` with `s`, without shifting anything.
+    ///
+    /// Succeeds only if the `s.len()` bytes from `char_idx` are whole chars of the content,
+    /// so the length never changes. A fast path for updating fixed-width fields.
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) - the span was overwritten
+    /// Err(()) - `char_idx` is out of range, or the span doesn't end on a char boundary
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("[ ] task");
+    /// assert_eq!(s.overwrite_at(0, "[x]"), Ok(()));
+    /// assert_eq!(s.as_str(), "[x] task");
+    /// assert_eq!(s.overwrite_at(4, "tasks"), Err(()));
+    /// ```
+    #[allow(clippy::result_unit_err)]
+    pub fn overwrite_at(&mut self, char_idx: usize, s: &str) -> Result<(), ()> {
+        let start = self.char_boundaries().nth(char_idx).ok_or(())?;
+        let end = start + s.len();
+        if end > self.len || !self.is_char_boundary(end) {
+            return Err(());
+        }
+        self.buffer[start..end].copy_from_slice(s.as_bytes());
+        Ok(())
+    }
+
+    /// Truncates the string to at most `byte_len` bytes.
+    ///
+    /// If `byte_len` is not on a char boundary, backs up to the start of that char,
+    /// so the result is never longer than `byte_len`. Has no effect if `byte_len >= bytes_len()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("ёжик");
+    /// s.truncate_bytes_floor(5);
+    /// assert_eq!(s.as_str(), "ёж");
+    /// ```
+    pub const fn truncate_bytes_floor(&mut self, byte_len: usize) {
+        if byte_len < self.len {
+            self.len = utf8_floor(&self.buffer, byte_len);
+        }
+    }
+
+    /// Truncates the string to exactly `byte_len` bytes, expecting a char boundary there.
+    ///
+    /// For callers that track byte offsets themselves. Behaves like
+    /// [`MicroStr::truncate_bytes_floor`] in release builds.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `byte_len < bytes_len()` is not on a char boundary.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("ёжик");
+    /// s.truncate_bytes_exact(4);
+    /// assert_eq!(s.as_str(), "ёж");
+    /// ```
+    pub const fn truncate_bytes_exact(&mut self, byte_len: usize) {
+        debug_assert!(
+            byte_len >= self.len || !is_utf8_continuation(self.buffer[byte_len]),
+            "`byte_len` is not on a char boundary"
+        );
+        self.truncate_bytes_floor(byte_len);
+    }
+
+    /// Shortens the string to at most `max_chars` chars, marking the cut with `'…'`.
+    ///
+    /// If the content is longer than `max_chars`, it is truncated to `max_chars - 1` chars
+    /// and `'…'` is appended. If `'…'` (3 bytes) doesn't fit the capacity after that,
+    /// more chars are removed to make room. With a capacity below 3 bytes, the content
+    /// is just truncated to `max_chars`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("Hello, world", 16);
+    /// s.truncate_ellipsis(6);
+    /// assert_eq!(s.as_str(), "Hello…");
+    /// ```
+    pub fn truncate_ellipsis(&mut self, max_chars: usize) {
+        const ELLIPSIS: char = '…';
+        if self.len() <= max_chars {
+            return;
+        }
+        if max_chars == 0 || CAP < ELLIPSIS.len_utf8() {
+            self.truncate(max_chars);
+            return;
+        }
+        self.truncate(max_chars - 1);
+        if !self.can_push(ELLIPSIS) {
+            self.len = utf8_floor(&self.buffer, CAP - ELLIPSIS.len_utf8());
+        }
+        // SAFETY: there is room for the ellipsis after the truncation above
+        unsafe { self.push_unchecked(ELLIPSIS) };
+    }
+
+    /// Truncates the string to `keep_chars` chars, then appends `s`.
+    ///
+    /// A common "update the suffix" operation, e.g. for a counter display.
+    /// Truncation follows [`MicroStr::truncate`], appending follows [`MicroStr::push_str`].
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) - full slice fits
+    /// Err(usize) - if only the first `n` bytes of `s` were appended due to capacity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("Count: 9", 10);
+    /// assert_eq!(s.replace_tail(7, "10"), Ok(()));
+    /// assert_eq!(s.as_str(), "Count: 10");
+    /// ```
+    pub fn replace_tail(&mut self, keep_chars: usize, s: &str) -> Result<(), usize> {
+        self.truncate(keep_chars);
+        self.push_str(s)
+    }
+
+    /// Removes all leading and trailing occurrences of `ch` in place.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("\"value\"");
+    /// s.trim_matches_char('"');
+    /// assert_eq!(s.as_str(), "value");
+    /// ```
+    pub fn trim_matches_char(&mut self, ch: char) {
+        let trimmed = self.trim_matches(ch);
+        let start = trimmed.as_ptr() as usize - self.as_ptr() as usize;
+        self.len = start + trimmed.len();
+        self.delete_byte_range(0, start);
+    }
+
+    /// Removes all chars matching `f` in place.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("+7 (999) 123-45-67");
+    /// s.strip_chars(|c| !c.is_ascii_digit());
+    /// assert_eq!(s.as_str(), "79991234567");
+    /// ```
+    pub fn strip_chars<F: Fn(char) -> bool>(&mut self, f: F) {
+        let mut write = 0;
+        let mut read = 0;
+        while read < self.len {
+            // SAFETY: `read` is always on a char boundary inside the content
+            let ch = unsafe { self.char_at_unchecked(read) };
+            let ch_len = ch.len_utf8();
+            if !f(ch) {
+                self.buffer.copy_within(read..read + ch_len, write);
+                write += ch_len;
+            }
+            read += ch_len;
+        }
+        self.len = write;
+    }
+
+    /// Removes ASCII control chars (including DEL), except `'\n'`, in place.
+    ///
+    /// Sanitizes terminal and log output: escape sequences lose their `ESC` byte,
+    /// so they are no longer interpreted. Use [`MicroStr::strip_chars`] for a custom set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("a\tb\r\nc\x07");
+    /// s.strip_control_chars();
+    /// assert_eq!(s.as_str(), "ab\nc");
+    /// ```
+    pub fn strip_control_chars(&mut self) {
+        self.strip_chars(|ch| ch.is_ascii_control() && ch != '\n');
+    }
+
+    /// Centers the content within `width` chars by adding `fill` on both sides.
+    ///
+    /// If the fill count is odd, the extra `fill` goes to the right.
+    /// If the capacity is exhausted, only the fill chars that fit are added,
+    /// still split evenly between both sides.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("hi", 8);
+    /// s.pad_center(7, '*');
+    /// assert_eq!(s.as_str(), "**hi***");
+    /// ```
+    pub fn pad_center(&mut self, width: usize, fill: char) {
+        let chars = self.len();
+        if chars >= width {
+            return;
+        }
+        let fill_len = fill.len_utf8();
+        let fit = const_min(width - chars, self.extra_capacity() / fill_len);
+        let (left, right) = (fit / 2, fit - fit / 2);
+
+        let shift = left * fill_len;
+        self.buffer.copy_within(0..self.len, shift);
+        let fill_bytes = char_to_bytes_utf8(fill);
+        for chunk in self.buffer[..shift].chunks_exact_mut(fill_len) {
+            chunk.copy_from_slice(&fill_bytes[..fill_len]);
+        }
+        self.len += shift;
+        self.append_char_n(fill, right);
+    }
+
+    /// Applies `f` to each ASCII byte in place, leaving multi-byte chars untouched.
+    ///
+    /// To preserve UTF-8 validity, a byte is kept unchanged if `f` maps it to a non-ASCII value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("PIN: 1234, код 56");
+    /// s.map_ascii_bytes(|b| if b.is_ascii_digit() { b'*' } else { b });
+    /// assert_eq!(s.as_str(), "PIN: ****, код **");
+    /// ```
+    pub fn map_ascii_bytes<F: Fn(u8) -> u8>(&mut self, f: F) {
+        for byte in self.as_mut_bytes() {
+            if byte.is_ascii() {
+                let mapped = f(*byte);
+                if mapped.is_ascii() {
+                    *byte = mapped;
+                }
+            }
+        }
+    }
+
+    /// Drops or replaces every char in place, as decided by `f`.
+    ///
+    /// `None` drops the char, `Some(c)` replaces it with `c`, which may have a different width.
+    /// If the replacements are wider and overflow the capacity, the result is **truncated**
+    /// at the last char that fits.
+    ///
+    /// Chars are compacted in place. Only if a replacement doesn't fit even after the unread
+    /// content is moved to the end of the buffer, the rest is read from a copy of the buffer,
+    /// which costs `CAP` bytes of stack: later chars may still be dropped and free the room.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("a-b-c", 8);
+    /// s.map_retain(|c| match c {
+    ///     '-' => None,
+    ///     'b' => Some('ж'),
+    ///     c => Some(c),
+    /// });
+    /// assert_eq!(s.as_str(), "aжc");
+    /// ```
+    pub fn map_retain<F: FnMut(char) -> Option<char>>(&mut self, mut f: F) {
+        // Compact forward in the same buffer, the write cursor never passes the read cursor
+        let (mut read, mut write, mut end) = (0, 0, self.len);
+        while read < end {
+            // SAFETY: `read` is always on a char boundary of the unread content
+            let ch = unsafe { self.char_at_unchecked(read) };
+            read += ch.len_utf8();
+            let Some(mapped) = f(ch) else { continue };
+            let width = mapped.len_utf8();
+            if write + width > read {
+                // Wider than what was consumed: move the unread tail to the end of the buffer
+                let tail = end - read;
+                self.buffer.copy_within(read..end, CAP - tail);
+                (read, end) = (CAP - tail, CAP);
+            }
+            if write + width > read {
+                // Still overlaps the unread tail. Output can't be stopped here, as later
+                // chars may be dropped, so the rest is read from a `CAP`-sized stack copy
+                let rest = self.buffer;
+                // SAFETY: `read..end` is the unread part of the original content
+                let rest = unsafe { from_utf8_unchecked(&rest[read..end]) };
+                self.len = write;
+                for ch in Some(mapped).into_iter().chain(rest.chars().filter_map(&mut f)) {
+                    if self.push(ch).is_err() {
+                        break;
+                    }
+                }
+                return;
+            }
+            mapped.encode_utf8(&mut self.buffer[write..write + width]);
+            write += width;
+        }
+        self.len = write;
+    }
+
+    /// Rotates the string in place so that the first `n` chars move to the end.
+    ///
+    /// `n` is taken modulo the number of chars.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("Привет");
+    /// s.rotate_left(2);
+    /// assert_eq!(s.as_str(), "иветПр");
+    /// ```
+    pub fn rotate_left(&mut self, n: usize) {
+        let chars = self.len();
+        if chars == 0 {
+            return;
+        }
+        let byte_idx = self.char_indices().nth(n % chars).map_or(0, |(idx, _)| idx);
+        self.as_mut_bytes().rotate_left(byte_idx);
+    }
+
+    /// Rotates the string in place so that the last `n` chars move to the front.
+    ///
+    /// `n` is taken modulo the number of chars.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("Привет");
+    /// s.rotate_right(2);
+    /// assert_eq!(s.as_str(), "етПрив");
+    /// ```
+    pub fn rotate_right(&mut self, n: usize) {
+        let chars = self.len();
+        if chars == 0 {
+            return;
+        }
+        self.rotate_left(chars - n % chars);
+    }
+
+    /// Moves the first char to the end, for scrolling marquee displays.
     ///
-    /// You must ensure that any modifications result in valid UTF-8.
+    /// Same as `rotate_left(1)`, without counting the chars.
+    /// After `char_count()` steps, the content is back to the original.
     ///
     /// # Example
     ///
     /// ```rust
     /// use microstr::*;
-    /// let mut s = microstr!("abc", 10);
-    /// let bytes = s.as_mut_bytes();
-    /// bytes[0] = b'x';
-    /// assert_eq!(s.as_str(), "xbc");
+    /// let mut s = microstr!("News: ");
+    /// s.marquee_step();
+    /// assert_eq!(s.as_str(), "ews: N");
     /// ```
-    pub fn as_mut_bytes(&mut self) -> &mut [u8] {
-        &mut self.buffer[..self.len]
+    pub fn marquee_step(&mut self) {
+        if self.len == 0 {
+            return;
+        }
+        // SAFETY: the content is not empty, so a char starts at 0
+        let first = unsafe { self.char_at_unchecked(0) };
+        self.delete_byte_range(0, first.len_utf8());
+        // SAFETY: the bytes of `first` were just freed
+        unsafe { self.push_unchecked(first) };
     }
 
-    /// Consumes the `MicroStr` and returns the raw byte buffer.
+    /// Replaces all non-overlapping occurrences of `from` with `to` in place.
     ///
-    /// The buffer is exactly `CAP` bytes long. Unused bytes are unspecified.
+    /// Occurrences are searched left to right, like [`str::replace`].
+    /// An empty `from` matches nothing.
+    ///
+    /// If the result exceeds the capacity, it is **truncated** to fit,
+    /// ensuring UTF-8 validity (does not split multi-byte characters).
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(n)` - all `n` occurrences were replaced.
+    /// - `Err(n)` - the result was truncated, only the first `n` replacements are fully kept.
     ///
     /// # Example
     ///
     /// ```rust
     /// use microstr::*;
-    /// let s = microstr!("Hi", 8);
-    /// let buf = s.into_raw_buffer();
-    /// assert_eq!(&buf[..2], b"Hi");
+    /// let mut s = microstr!("a-b-c", 8);
+    /// assert_eq!(s.replace_all("-", " + "), Err(2));
+    /// assert_eq!(s.as_str(), "a + b + "); // Truncated
+    ///
+    /// let mut s = microstr!("a::b::c");
+    /// assert_eq!(s.replace_all("::", "/"), Ok(2));
+    /// assert_eq!(s.as_str(), "a/b/c");
     /// ```
-    pub const fn into_raw_buffer(self) -> [u8; CAP] {
-        self.buffer
-    }
+    pub fn replace_all(&mut self, from: &str, to: &str) -> Result<usize, usize> {
+        if from.is_empty() {
+            return Ok(0);
+        }
+        let (from_bytes, to_bytes) = (from.as_bytes(), to.as_bytes());
 
-    /* ##### MODIFICATORS ##### */
+        if to.len() <= from.len() {
+            // Shrinking: compact left to right, the result always fits
+            let (mut read, mut write, mut count) = (0, 0, 0);
+            while let Some(idx) = find_bytes(&self.buffer[read..self.len], from_bytes) {
+                self.buffer.copy_within(read..read + idx, write);
+                write += idx;
+                self.buffer[write..write + to.len()].copy_from_slice(to_bytes);
+                write += to.len();
+                read += idx + from.len();
+                count += 1;
+            }
+            self.buffer.copy_within(read..self.len, write);
+            self.len = write + self.len - read;
+            return Ok(count);
+        }
 
-    /// Clears str to `default` state.
-    /// 
-    /// Sets length as 0 and first byte b'\0'
-    /// 
-    /// # Example
-    /// 
-    /// ```rust
-    /// use microstr::*;
-    /// let mut s = microstr!("Clear me!");
-    /// s.clear();
-    /// assert_eq!(s.as_str(), "");
-    /// ```
-    #[inline]
-    pub const fn clear(&mut self) {
-        self.len = 0;
-        if CAP > 0 {
-            self.buffer[0] = b'\0';
+        // Growing: place segments right to left at their final positions,
+        // so unread source bytes are never overwritten. Bytes past `CAP` are dropped.
+        // Occurrences are marked in one forward pass, so each is found again by
+        // a backward scan of its own segment only.
+        let growth = to.len() - from.len();
+        let count = self.mark_occurrences(from_bytes);
+        let total = self.len + count * growth;
+        let mut done = 0;
+        let mut seg_end = self.len;
+        for k in (0..count).rev() {
+            let mut occ = seg_end - 1;
+            while self.buffer[occ] != OCCURRENCE_MARK {
+                occ -= 1;
+            }
+            let seg_start = occ + from.len();
+            self.copy_within_clipped(seg_start..seg_end, seg_start + (k + 1) * growth);
+            let to_start = occ + k * growth;
+            if to_start < CAP {
+                let fit = const_min(to.len(), CAP - to_start);
+                self.buffer[to_start..to_start + fit].copy_from_slice(&to_bytes[..fit]);
+            }
+            if to_start + to.len() <= CAP {
+                done += 1;
+            }
+            seg_end = occ;
         }
+
+        if total <= CAP {
+            self.len = total;
+            return Ok(count);
+        }
+        self.len = utf8_floor(&self.buffer, CAP);
+        Err(done)
     }
 
-    /// Truncates the string by index of **char**.
+    /// Inserts `prefix` at the start of every line in place.
     ///
-    /// If `char_idx` is greater than or equal to the number of characters,
-    /// this is a no-op.
+    /// Lines are the same as in [`str::lines`]: nothing is inserted
+    /// after a final `'\n'`, and an empty string has no lines.
+    ///
+    /// If the result exceeds the capacity, it is **truncated** to fit,
+    /// ensuring UTF-8 validity (does not split multi-byte characters).
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` - every line was indented.
+    /// - `Err(n)` - the result was truncated, only the first `n` lines are fully kept.
     ///
     /// # Example
     ///
     /// ```rust
     /// use microstr::*;
-    /// let mut s = microstr!("💖Rust", 10);
-    /// s.truncate(1);
-    /// assert_eq!(s.as_str(), "💖");
+    /// let mut s = microstr!("fn main() {\n}\n", 32);
+    /// assert_eq!(s.indent("    "), Ok(()));
+    /// assert_eq!(s.as_str(), "    fn main() {\n    }\n");
+    ///
+    /// let mut s = microstr!("a\nb", 5);
+    /// assert_eq!(s.indent("> "), Err(1));
+    /// assert_eq!(s.as_str(), "> a\n>");
     /// ```
-    pub fn truncate(&mut self, char_idx : usize) {
-        if char_idx > self.len() { return; }
-        let mut byte_idx = 0;
-        for (idx, ch) in self.chars().enumerate() {
-            if idx == char_idx {
-                break;
+    pub fn indent(&mut self, prefix: &str) -> Result<(), usize> {
+        let count = self.line_count();
+        if prefix.is_empty() || count == 0 {
+            return Ok(());
+        }
+
+        // Place lines right to left at their final positions, like in `replace_all`,
+        // so unread source bytes are never overwritten. Bytes past `CAP` are dropped.
+        let total = self.len + count * prefix.len();
+        let mut done = 0;
+        let mut seg_end = self.len;
+        for k in (0..count).rev() {
+            let seg_start = match k {
+                0 => 0,
+                // The line ends either with its '\n' or with the content
+                _ => rfind_bytes(&self.buffer[..seg_end - 1], b"\n").map_or(0, |idx| idx + 1),
+            };
+            self.copy_within_clipped(seg_start..seg_end, seg_start + (k + 1) * prefix.len());
+            let prefix_start = seg_start + k * prefix.len();
+            if prefix_start < CAP {
+                let fit = const_min(prefix.len(), CAP - prefix_start);
+                self.buffer[prefix_start..prefix_start + fit].copy_from_slice(&prefix.as_bytes()[..fit]);
             }
-            byte_idx += ch.len_utf8();
+            if seg_end + (k + 1) * prefix.len() <= CAP {
+                done += 1;
+            }
+            seg_end = seg_start;
         }
-        // SAFETY:
-        // - `byte_idx` is computed by summing `ch.len_utf8()` for valid UTF-8 characters.
-        // - The loop stops when `idx == char_idx`, so `byte_idx` corresponds to the start of the next char.
-        // - Since `char_idx < self.len()`, we know `byte_idx < self.len() <= CAP`.
-        // - `self.as_mut_ptr()` is valid for `CAP` bytes.
-        // - `byte_idx < CAP`, so `self.as_mut_ptr().add(byte_idx)` is in bounds.
-        // - We write `0` (null terminator) — safe for UTF-8 and FFI.
-        unsafe { self.as_mut_ptr().add(byte_idx).write(0) };
-        self.len = byte_idx;
+
+        if total <= CAP {
+            self.len = total;
+            return Ok(());
+        }
+        self.len = utf8_floor(&self.buffer, CAP);
+        Err(done)
+    }
+}
+
+impl<const CAP: usize> MicroStr<CAP> {
+    /// Returns the number of non-overlapping occurrences of `needle` in content
+    fn count_occurrences(&self, needle: &[u8]) -> usize {
+        let (mut count, mut from) = (0, 0);
+        while let Some(idx) = find_bytes(&self.buffer[from..self.len], needle) {
+            from += idx + needle.len();
+            count += 1;
+        }
+        count
+    }
+
+    /// Overwrites the first byte of every non-overlapping occurrence of `needle`
+    /// with [`OCCURRENCE_MARK`] and returns their number.
+    ///
+    /// Leaves the content invalid UTF-8: the caller must overwrite every occurrence.
+    fn mark_occurrences(&mut self, needle: &[u8]) -> usize {
+        let (mut count, mut from) = (0, 0);
+        while let Some(idx) = find_bytes(&self.buffer[from..self.len], needle) {
+            self.buffer[from + idx] = OCCURRENCE_MARK;
+            from += idx + needle.len();
+            count += 1;
+        }
+        count
+    }
+
+    /// Deletes the bytes `start..end` of the content, shifting the tail left to close the gap.
+    ///
+    /// Shared by the methods removing a single range (`trim_matches_char`, `marquee_step`).
+    /// Methods removing many ranges (`strip_chars`, `replace_all`, `map_retain`) compact
+    /// in one pass instead, as calling this per range would be quadratic.
+    ///
+    /// Panics if the range is out of the content or doesn't lie on char boundaries,
+    /// so the content always stays valid UTF-8.
+    fn delete_byte_range(&mut self, start: usize, end: usize) {
+        assert!(start <= end && end <= self.len, "byte range is out of the content");
+        assert!(
+            self.as_str().is_char_boundary(start) && self.as_str().is_char_boundary(end),
+            "byte range is not on char boundaries"
+        );
+        self.buffer.copy_within(end..self.len, start);
+        self.len -= end - start;
+    }
+
+    /// Copies `src` range of buffer to `dest`, dropping bytes that fall past `CAP`
+    fn copy_within_clipped(&mut self, src: Range<usize>, dest: usize) {
+        if dest >= CAP {
+            return;
+        }
+        let count = const_min(src.end - src.start, CAP - dest);
+        self.buffer.copy_within(src.start..src.start + count, dest);
     }
 }
 
@@ -631,10 +2844,35 @@ impl<const A: usize, const B: usize> PartialEq<MicroStr<B>> for MicroStr<A> {
     fn eq(&self, other: &MicroStr<B>) -> bool {
         self.as_str() == other.as_str()
     }
+}
+
+impl<const CAP: usize> Eq for MicroStr<CAP> {}
+
+impl<const A: usize, const B: usize> PartialOrd<MicroStr<B>> for MicroStr<A> {
+    /// Compares two `MicroStr`s lexicographically by content, like `str`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// assert!(microstr!("apple", 8) < microstr!("banana", 6));
+    /// ```
+    fn partial_cmp(&self, other: &MicroStr<B>) -> Option<Ordering> {
+        Some(self.as_str().cmp(other.as_str()))
+    }
+}
+
+impl<const CAP: usize> Ord for MicroStr<CAP> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
 
-    /// Compares two `MicroStr`s for inequality by content.
-    fn ne(&self, other: &MicroStr<B>) -> bool {
-        self.as_str() != other.as_str()
+impl<const CAP: usize> Hash for MicroStr<CAP> {
+    /// Hashes the content the same way as `str`, so equal `MicroStr`s of
+    /// different capacities hash equally.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
     }
 }
 
@@ -674,20 +2912,281 @@ impl<const CAP: usize> DerefMut for MicroStr<CAP> {
     }
 }
 
-impl<const CAP: usize> fmt::Write for MicroStr<CAP> {
-    fn write_char(&mut self, c: char) -> fmt::Result {
-        self.push(c).map_err(|_| fmt::Error)
+impl<const CAP: usize> Index<usize> for MicroStr<CAP> {
+    type Output = u8;
+
+    /// Returns the **byte** (not the char) at `idx`, like indexing `as_bytes()`.
+    ///
+    /// Range indexing still slices the `str`, see the range impls below.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx >= bytes_len()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("ёж");
+    /// assert_eq!(s[0], 0xD1); // first byte of 'ё'
+    /// assert_eq!(&s[2..], "ж");
+    /// ```
+    #[inline]
+    fn index(&self, idx: usize) -> &u8 {
+        &self.as_bytes()[idx]
+    }
+}
+
+// `Index<usize>` stops auto-deref for all other index types, so every other
+// `SliceIndex<str>` type has to be forwarded to `str` explicitly. A blanket
+// `impl<I: SliceIndex<str>>` would conflict with `Index<usize>`.
+macro_rules! impl_index_range {
+    ($($range:ty),*) => {$(
+        impl<const CAP: usize> Index<$range> for MicroStr<CAP> {
+            type Output = str;
+
+            #[inline]
+            fn index(&self, range: $range) -> &str {
+                &self.as_str()[range]
+            }
+        }
+
+        impl<const CAP: usize> IndexMut<$range> for MicroStr<CAP> {
+            #[inline]
+            fn index_mut(&mut self, range: $range) -> &mut str {
+                &mut self.as_str_mut()[range]
+            }
+        }
+    )*};
+}
+
+impl_index_range!(
+    Range<usize>,
+    RangeFrom<usize>,
+    RangeTo<usize>,
+    RangeFull,
+    RangeInclusive<usize>,
+    RangeToInclusive<usize>,
+    (Bound<usize>, Bound<usize>)
+);
+
+impl<const CAP: usize> AsRef<str> for MicroStr<CAP> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const CAP: usize, const B: usize> Extend<MicroStr<B>> for MicroStr<CAP> {
+    /// Appends the contents of other `MicroStr`s of any capacity.
+    ///
+    /// Stops at the first one that doesn't fit, which is **truncated**
+    /// without splitting multi-byte characters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut path = microstr!("/usr", 16);
+    /// path.extend([microstr!("/local", 8), microstr!("/bin", 8)]);
+    /// assert_eq!(path.as_str(), "/usr/local/bin");
+    /// ```
+    fn extend<I: IntoIterator<Item = MicroStr<B>>>(&mut self, iter: I) {
+        for s in iter {
+            if self.push_str(s.as_str()).is_err() {
+                break;
+            }
+        }
+    }
+}
+
+impl<'a, const CAP: usize, const B: usize> Extend<&'a MicroStr<B>> for MicroStr<CAP> {
+    /// Appends the contents of other `MicroStr`s of any capacity, see `Extend<MicroStr<B>>`.
+    fn extend<I: IntoIterator<Item = &'a MicroStr<B>>>(&mut self, iter: I) {
+        for s in iter {
+            if self.push_str(s.as_str()).is_err() {
+                break;
+            }
+        }
+    }
+}
+
+impl<const CAP: usize, const B: usize> From<&MicroStr<B>> for MicroStr<CAP> {
+    /// Copies a `MicroStr` of any capacity.
+    ///
+    /// If the content doesn't fit, it is **truncated** without splitting multi-byte characters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let big = microstr!("Привет", 32);
+    /// let small: MicroStr<8> = (&big).into();
+    /// assert_eq!(small.as_str(), "Прив");
+    /// ```
+    fn from(s: &MicroStr<B>) -> Self {
+        Self::from_const(s.as_str())
+    }
+}
+
+impl<const CAP: usize> TryFrom<&[u8]> for MicroStr<CAP> {
+    type Error = FromBytesError;
+
+    /// Constructs a `MicroStr` from a byte slice, without truncation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = MicroStr::<4>::try_from("Кот".as_bytes());
+    /// assert_eq!(s, Err(FromBytesError::Overflow));
+    ///
+    /// let s = MicroStr::<6>::try_from("Кот".as_bytes()).unwrap();
+    /// assert_eq!(s.as_str(), "Кот");
+    /// ```
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() > CAP {
+            return Err(FromBytesError::Overflow);
+        }
+        let s = from_utf8(bytes).map_err(FromBytesError::InvalidUtf8)?;
+        // SAFETY: checked length
+        Ok(unsafe { Self::from_str_unchecked(s) })
+    }
+}
+
+/// An error returned when a `MicroStr` can't be constructed from bytes without loss.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromBytesError {
+    /// The bytes don't fit into the capacity.
+    Overflow,
+    /// The bytes are not valid UTF-8.
+    InvalidUtf8(Utf8Error),
+}
+
+impl fmt::Display for FromBytesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => f.write_str("bytes don't fit into `MicroStr` capacity"),
+            Self::InvalidUtf8(err) => fmt::Display::fmt(err, f),
+        }
     }
+}
 
-    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> fmt::Result {
-        self.push_str(args.as_str().ok_or(fmt::Error)?).map_err(|_| fmt::Error)
+impl<const CAP: usize> fmt::Write for MicroStr<CAP> {
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        // Every char takes at least 1 byte, so with `CAP == 0` this always fails
+        // before any pointer into the empty buffer is computed
+        if !self.can_push(c) {
+            return Err(fmt::Error);
+        }
+        // SAFETY: checked length
+        unsafe { self.push_unchecked(c) };
+        Ok(())
     }
 
     fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.reserve_exact_check(s.len()) {
+            // SAFETY: checked length
+            unsafe { self.push_str_unchecked(s) };
+            return Ok(());
+        }
         self.push_str(s).map_err(|_| fmt::Error)
     }
 }
 
+/// Display helper returned by [`MicroStr::display_truncated`]
+struct TruncatedDisplay<'a> {
+    s: &'a str,
+    max_chars: usize,
+}
+
+impl fmt::Display for TruncatedDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.s.char_indices().nth(self.max_chars) {
+            Some((byte_idx, _)) => {
+                f.write_str(&self.s[..byte_idx])?;
+                f.write_char('…')
+            }
+            None => f.write_str(self.s),
+        }
+    }
+}
+
+/// Concatenates two `MicroStr`s into a new `MicroStr` of capacity `C`.
+///
+/// Usable in const context. If `C` is too small, the result is **truncated**,
+/// ensuring UTF-8 validity (does not split multi-byte characters).
+///
+/// # Example
+///
+/// ```rust
+/// use microstr::*;
+/// const HOST: MicroStr<9> = microstr!("localhost");
+/// const PORT: MicroStr<5> = microstr!(":8080");
+/// const ADDR: MicroStr<14> = const_concat(&HOST, &PORT);
+/// assert_eq!(ADDR.as_str(), "localhost:8080");
+/// ```
+pub const fn const_concat<const A: usize, const B: usize, const C: usize>(a: &MicroStr<A>, b: &MicroStr<B>) -> MicroStr<C> {
+    let mut result = MicroStr::new();
+    if result.push_str(a.as_str()).is_ok() {
+        let _ = result.push_str(b.as_str());
+    }
+    result
+}
+
+/// Returns the length of the longest valid UTF-8 prefix of `bytes` not exceeding `max`.
+///
+/// Useful for streaming decode: the returned length never splits a multi-byte character
+/// and stops before the first invalid byte.
+///
+/// # Example
+///
+/// ```rust
+/// use microstr::*;
+/// let bytes = "Кот".as_bytes();
+/// assert_eq!(valid_utf8_prefix_len(bytes, 3), 2); // 'о' is not split
+/// assert_eq!(valid_utf8_prefix_len(b"ab\xffcd", 10), 2); // stops at invalid byte
+/// ```
+pub const fn valid_utf8_prefix_len(bytes: &[u8], max: usize) -> usize {
+    let (head, _) = bytes.split_at(const_min(bytes.len(), max));
+    match from_utf8(head) {
+        Ok(_) => head.len(),
+        Err(err) => err.valid_up_to(),
+    }
+}
+
+/// Returns the minimal `MicroStr` capacity that holds `s` without truncation.
+///
+/// This is the capacity `microstr!` picks when it is not given explicitly.
+///
+/// # Example
+///
+/// ```rust
+/// use microstr::*;
+/// const GREETING: &str = "Привет";
+/// const CAP: usize = min_capacity_for(GREETING);
+/// let s = MicroStr::<CAP>::from_const(GREETING);
+/// assert_eq!(CAP, 12);
+/// assert_eq!(s.extra_capacity(), 0);
+/// ```
+#[inline]
+pub const fn min_capacity_for(s: &str) -> usize {
+    s.len()
+}
+
+/// Returns `true` if `s` fits into `MicroStr<CAP>` without truncation.
+///
+/// # Example
+///
+/// ```rust
+/// use microstr::*;
+/// const { assert!(fits_in::<8>("Hello")) };
+/// assert!(!fits_in::<8>("Hello, world"));
+/// ```
+#[inline]
+pub const fn fits_in<const CAP: usize>(s: &str) -> bool {
+    min_capacity_for(s) <= CAP
+}
+
 /// Returns nearest less idx to get valid UTF-8
 const fn utf8_truncator(s: &str, idx : usize) -> usize {
     if idx >= s.len() { return s.len(); }
@@ -699,9 +3198,46 @@ const fn utf8_truncator(s: &str, idx : usize) -> usize {
         }
         i -= 1;
     }
-    return i;
+    i
+}
+
+
+/// Returns the largest idx <= `len` that doesn't split a char of `bytes[..len]`,
+/// assuming `bytes` was valid UTF-8 before being cut at `len`
+const fn utf8_floor(bytes: &[u8], len: usize) -> usize {
+    if len == 0 { return 0; }
+    let mut start = len - 1;
+    while start > 0 && is_utf8_continuation(bytes[start]) {
+        start -= 1;
+    }
+    if start + utf8_char_width(bytes[start]) > len {
+        start
+    } else {
+        len
+    }
 }
 
+/// Returns `true` if the char has the Unicode `White_Space` property, like [`char::is_whitespace`]
+const fn is_whitespace_const(c : char) -> bool {
+    matches!(c,
+        '\t'..='\r' | ' ' | '\u{85}' | '\u{A0}' | '\u{1680}' | '\u{2000}'..='\u{200A}'
+        | '\u{2028}' | '\u{2029}' | '\u{202F}' | '\u{205F}' | '\u{3000}'
+    )
+}
+
+/// A byte that never occurs in valid UTF-8, used to mark positions in the buffer
+const OCCURRENCE_MARK: u8 = 0xFF;
+
+/// Returns the length of UTF-8 sequence by its leading byte
+#[inline(always)]
+const fn utf8_char_width(lead : u8) -> usize {
+    match lead.leading_ones() {
+        0 => 1,
+        2 => 2,
+        3 => 3,
+        _ => 4,
+    }
+}
 
 /// Returns `true` if the byte is a UTF-8 continuation byte (10xxxxxx)
 #[inline(always)]
@@ -709,6 +3245,45 @@ const fn is_utf8_continuation(byte : u8) -> bool {
     byte & 0b1100_0000 == 0b1000_0000
 }
 
+/// Returns index of the first occurrence of `needle` in `haystack`
+const fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.len() > haystack.len() { return None; }
+    let mut i = 0;
+    while i + needle.len() <= haystack.len() {
+        if bytes_eq_at(haystack, i, needle) {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Returns index of the last occurrence of `needle` in `haystack`
+const fn rfind_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.len() > haystack.len() { return None; }
+    let mut i = haystack.len() - needle.len() + 1;
+    while i > 0 {
+        i -= 1;
+        if bytes_eq_at(haystack, i, needle) {
+            return Some(i);
+        }
+    }
+    None
+}
+
+/// Returns `true` if `haystack` contains `needle` starting at `idx`
+#[inline]
+const fn bytes_eq_at(haystack: &[u8], idx: usize, needle: &[u8]) -> bool {
+    let mut j = 0;
+    while j < needle.len() {
+        if haystack[idx + j] != needle[j] {
+            return false;
+        }
+        j += 1;
+    }
+    true
+}
+
 /// const-fn analog to min
 #[inline(always)]
 const fn const_min(a : usize, b : usize) -> usize {
@@ -0,0 +1,127 @@
+use super::MicroStr;
+
+/// A fixed-capacity, stack-allocated list of `MicroStr`s.
+///
+/// Holds up to `N` strings of capacity `CAP` each, e.g. command arguments
+/// in embedded environments.
+///
+/// # Example
+///
+/// ```rust
+/// use microstr::*;
+/// let mut args: MicroStrList<8, 2> = MicroStrList::new();
+/// assert!(args.push(microstr!("get", 8)).is_ok());
+/// assert!(args.push(microstr!("led", 8)).is_ok());
+/// assert!(args.push(microstr!("on", 8)).is_err()); // Full
+/// assert_eq!(args.get(1).unwrap().as_str(), "led");
+/// ```
+#[derive(Clone)]
+pub struct MicroStrList<const CAP: usize, const N: usize> {
+    items: [MicroStr<CAP>; N],
+    len: usize,
+}
+
+impl<const CAP: usize, const N: usize> MicroStrList<CAP, N> {
+    /// Creates an empty `MicroStrList`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let list: MicroStrList<16, 4> = MicroStrList::new();
+    /// assert!(list.is_empty());
+    /// assert_eq!(list.capacity(), 4);
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            items: [const { MicroStr::new() }; N],
+            len: 0,
+        }
+    }
+
+    /// Appends a string to the end of the list.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the string was added.
+    /// - `Err(s)` with the given string back if the list is full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut list: MicroStrList<4, 1> = MicroStrList::new();
+    /// assert!(list.push(microstr!("a", 4)).is_ok());
+    /// assert_eq!(list.push(microstr!("b", 4)).unwrap_err().as_str(), "b");
+    /// ```
+    pub fn push(&mut self, s: MicroStr<CAP>) -> Result<(), MicroStr<CAP>> {
+        if self.len == N {
+            return Err(s);
+        }
+        self.items[self.len] = s;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Returns the string at `idx`, or `None` if out of bounds.
+    #[inline]
+    pub fn get(&self, idx: usize) -> Option<&MicroStr<CAP>> {
+        self.as_slice().get(idx)
+    }
+
+    /// Returns an iterator over the stored strings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut list: MicroStrList<4, 4> = MicroStrList::new();
+    /// list.push(microstr!("a", 4)).unwrap();
+    /// list.push(microstr!("b", 4)).unwrap();
+    /// assert!(list.iter().map(|s| s.as_str()).eq(["a", "b"]));
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> core::slice::Iter<'_, MicroStr<CAP>> {
+        self.as_slice().iter()
+    }
+
+    /// Returns the stored strings as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[MicroStr<CAP>] {
+        &self.items[..self.len]
+    }
+
+    /// Returns the number of stored strings.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list holds no strings.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the maximum number of strings, `N`.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+}
+
+impl<const CAP: usize, const N: usize> Default for MicroStrList<CAP, N> {
+    /// Returns an empty `MicroStrList`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, const CAP: usize, const N: usize> IntoIterator for &'a MicroStrList<CAP, N> {
+    type Item = &'a MicroStr<CAP>;
+    type IntoIter = core::slice::Iter<'a, MicroStr<CAP>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
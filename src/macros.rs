@@ -20,7 +20,7 @@ macro_rules! microstr {
     ($s:expr) => {
         {
             const STR : &str = $s;
-            const LEN : usize = STR.len();
+            const LEN : usize = $crate::min_capacity_for(STR);
             unsafe { $crate::MicroStr::<{LEN}>::from_str_unchecked(STR) }
         }
     };
@@ -30,3 +30,78 @@ macro_rules! microstr {
         }
     };
 }
+
+#[macro_export]
+/// Creates an empty `MicroStr` with the given capacity.
+/// 
+/// Shorthand for `MicroStr::<CAP>::new()`, usable in const context.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use microstr::{microstr_new, MicroStr};
+/// const EMPTY: MicroStr<32> = microstr_new!(32);
+/// let mut s = microstr_new!(16);
+/// s.push_str("Hi");
+/// 
+/// assert_eq!(EMPTY.capacity(), 32);
+/// assert!(EMPTY.is_empty());
+/// assert_eq!(s.as_str(), "Hi");
+/// ```
+macro_rules! microstr_new {
+    ($cap:expr) => {
+        $crate::MicroStr::<{$cap}>::new()
+    };
+}
+
+#[macro_export]
+/// Creates a `MicroStr` with the given capacity, failing compilation if the string does not fit.
+/// 
+/// Unlike `microstr!("...", cap)`, which silently truncates, this protects
+/// configuration literals from accidental truncation.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use microstr::microstr_exact;
+/// let s = microstr_exact!("Hello", 8);
+/// assert_eq!(s.as_str(), "Hello");
+/// assert_eq!(s.capacity(), 8);
+/// ```
+/// 
+/// An over-long literal doesn't compile:
+/// 
+/// ```compile_fail
+/// use microstr::microstr_exact;
+/// let s = microstr_exact!("Hello, world", 5);
+/// ```
+macro_rules! microstr_exact {
+    ($s:expr, $cap:expr) => {
+        {
+            const STR : &str = $s;
+            const _ : () = assert!($crate::fits_in::<{$cap}>(STR), "string does not fit into `MicroStr` capacity");
+            unsafe { $crate::MicroStr::<{$cap}>::from_str_unchecked(STR) }
+        }
+    };
+}
+
+#[macro_export]
+/// Joins string literals with a separator at compile time into a `MicroStr`.
+/// 
+/// The capacity is exactly the length of the joined string, like in `microstr!`.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use microstr::{microstr_join, MicroStr};
+/// const PATH: MicroStr<14> = microstr_join!("/", "", "usr", "local", "bin");
+/// assert_eq!(PATH.as_str(), "/usr/local/bin");
+/// ```
+macro_rules! microstr_join {
+    ($sep:literal $(,)?) => {
+        $crate::microstr!("")
+    };
+    ($sep:literal, $first:literal $(, $rest:literal)* $(,)?) => {
+        $crate::microstr!(concat!($first $(, $sep, $rest)*))
+    };
+}
@@ -0,0 +1,28 @@
+use core::fmt;
+use super::MicroStr;
+
+/// A `no_std` analog of [`ToString`](https://doc.rust-lang.org/std/string/trait.ToString.html)
+/// targeting `MicroStr`.
+///
+/// Implemented for every type that implements [`fmt::Display`].
+///
+/// # Example
+///
+/// ```rust
+/// use microstr::*;
+/// let s: MicroStr<8> = 42.to_microstr();
+/// assert_eq!(s.as_str(), "42");
+/// ```
+pub trait MicroFmt {
+    /// Formats the value into a new `MicroStr`.
+    ///
+    /// If the output is longer than the capacity, it is **truncated** to fit,
+    /// ensuring UTF-8 validity (does not split multi-byte characters).
+    fn to_microstr<const CAP: usize>(&self) -> MicroStr<CAP>;
+}
+
+impl<T: fmt::Display + ?Sized> MicroFmt for T {
+    fn to_microstr<const CAP: usize>(&self) -> MicroStr<CAP> {
+        MicroStr::from_fmt(format_args!("{}", self))
+    }
+}
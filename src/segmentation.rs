@@ -0,0 +1,55 @@
+use unicode_segmentation::UnicodeSegmentation;
+use super::MicroStr;
+
+impl<const CAP: usize> MicroStr<CAP> {
+    /// Returns an iterator over the extended grapheme clusters of the string.
+    ///
+    /// A grapheme cluster is a user-perceived character, e.g. a letter with
+    /// combining marks or a ZWJ emoji sequence.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("e\u{301}👍🏽");
+    /// assert!(s.graphemes().eq(["e\u{301}", "👍🏽"]));
+    /// ```
+    pub fn graphemes(&self) -> impl Iterator<Item = &str> {
+        UnicodeSegmentation::graphemes(self.as_str(), true)
+    }
+
+    /// Returns the number of extended grapheme clusters in the string.
+    ///
+    /// This is computed by iterating over `graphemes()`, so it's O(n).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("e\u{301}👍🏽");
+    /// assert_eq!(s.len(), 4);
+    /// assert_eq!(s.grapheme_len(), 2);
+    /// ```
+    pub fn grapheme_len(&self) -> usize {
+        self.graphemes().count()
+    }
+
+    /// Truncates the string to the first `n` extended grapheme clusters.
+    ///
+    /// Unlike [`MicroStr::truncate`], combining sequences and emoji sequences are never split.
+    /// If `n` is greater than or equal to the number of grapheme clusters, this is a no-op.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("e\u{301}te\u{301}");
+    /// s.truncate_graphemes(1);
+    /// assert_eq!(s.as_str(), "e\u{301}");
+    /// ```
+    pub fn truncate_graphemes(&mut self, n: usize) {
+        if let Some((byte_idx, _)) = self.as_str().grapheme_indices(true).nth(n) {
+            self.len = byte_idx;
+        }
+    }
+}
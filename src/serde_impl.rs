@@ -0,0 +1,64 @@
+use core::fmt;
+use serde::{
+    de::{self, Deserialize, Deserializer, Visitor},
+    ser::{Serialize, Serializer},
+};
+use super::MicroStr;
+
+impl<const CAP: usize> Serialize for MicroStr<CAP> {
+    /// Serializes the content as a string.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// A borrowed view of a `MicroStr` that serializes the content as bytes instead of a string.
+///
+/// For binary formats that prefer byte fields. Deserializing `MicroStr` accepts bytes
+/// as well, so the content round-trips.
+///
+/// # Example
+///
+/// ```rust
+/// use microstr::*;
+/// use serde_test::{assert_ser_tokens, Token};
+///
+/// let name = microstr!("sensor", 8);
+/// assert_ser_tokens(&AsBytes(&name), &[Token::Bytes(b"sensor")]);
+/// ```
+#[derive(Clone, Copy)]
+pub struct AsBytes<'a, const CAP: usize>(pub &'a MicroStr<CAP>);
+
+impl<const CAP: usize> Serialize for AsBytes<'_, CAP> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0.as_bytes())
+    }
+}
+
+impl<'de, const CAP: usize> Deserialize<'de> for MicroStr<CAP> {
+    /// Deserializes a string, or UTF-8 bytes for formats that store strings as bytes.
+    ///
+    /// Fails if the content doesn't fit in `CAP` bytes, instead of truncating.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(MicroStrVisitor)
+    }
+}
+
+struct MicroStrVisitor<const CAP: usize>;
+
+impl<const CAP: usize> Visitor<'_> for MicroStrVisitor<CAP> {
+    type Value = MicroStr<CAP>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a string of at most {CAP} bytes")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        MicroStr::from_str(v).map_err(|_| E::invalid_length(v.len(), &self))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        let s = core::str::from_utf8(v).map_err(|_| E::invalid_value(de::Unexpected::Bytes(v), &self))?;
+        self.visit_str(s)
+    }
+}
@@ -1,11 +1,11 @@
-use std::fmt;
+use std::{ffi::{CString, NulError}, fmt};
 use core::fmt::Formatter;
-use super::MicroStr;
+use super::{MicroStr, FromBytesError};
 
 impl<const CAP: usize> fmt::Debug for MicroStr<CAP> {
     /// Formats the `MicroStr` for debugging.
     ///
-    /// Output format: `MicroStr<{CAP}>"{content}"`.
+    /// Output format: `MicroStr<{CAP}>{"content"}`, the content is escaped like `str`'s `Debug`.
     ///
     /// # Example
     ///
@@ -13,16 +13,19 @@ impl<const CAP: usize> fmt::Debug for MicroStr<CAP> {
     /// use microstr::*;
     /// let s = microstr!("test", 10);
     /// assert_eq!(format!("{:?}", s), "MicroStr<10>{\"test\"}");
+    /// let s = microstr!("say \"hi\"\n");
+    /// assert_eq!(format!("{:?}", s), r#"MicroStr<9>{"say \"hi\"\n"}"#);
     /// ```
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "MicroStr<{}>{{\"{}\"}}", CAP, self.as_str())
+        write!(f, "MicroStr<{}>{{{:?}}}", CAP, self.as_str())
     }
 }
 
 impl<const CAP: usize> fmt::Display for MicroStr<CAP> {
     /// Formats the `MicroStr` as a regular string.
     ///
-    /// Useful for printing.
+    /// Useful for printing. Width, fill, alignment and precision
+    /// are respected like for `str`, precision truncates by chars.
     ///
     /// # Example
     ///
@@ -30,9 +33,11 @@ impl<const CAP: usize> fmt::Display for MicroStr<CAP> {
     /// use microstr::*;
     /// let s = microstr!("Hello", 10);
     /// assert_eq!(format!("{}", s), "Hello");
+    /// assert_eq!(format!("{:>7}", s), "  Hello");
+    /// assert_eq!(format!("{:.2}", s), "He");
     /// ```
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        f.write_str(self.as_str())
+        f.pad(self.as_str())
     }
 }
 
@@ -78,3 +83,53 @@ impl<const CAP: usize> From<MicroStr<CAP>> for String {
         result
     }
 }
+
+impl<const CAP: usize> MicroStr<CAP> {
+    /// Copies the content into a right-sized `Vec<u8>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("bytes", 16);
+    /// let vec = s.to_vec();
+    /// assert_eq!(vec, b"bytes");
+    /// assert_eq!(vec.capacity(), 5);
+    /// ```
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+
+    /// Consumes the `MicroStr` and returns its content as a right-sized `Vec<u8>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let vec = microstr!("bytes", 16).into_vec();
+    /// assert_eq!(vec, b"bytes");
+    /// ```
+    pub fn into_vec(self) -> Vec<u8> {
+        self.to_vec()
+    }
+
+    /// Copies the content into an owned, nul-terminated `CString`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NulError`] if the content contains an interior nul byte.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let c = microstr!("tty0").to_cstring().unwrap();
+    /// assert_eq!(c.as_bytes_with_nul(), b"tty0\0");
+    /// assert!(microstr!("a\0b").to_cstring().is_err());
+    /// ```
+    pub fn to_cstring(&self) -> Result<CString, NulError> {
+        CString::new(self.as_bytes())
+    }
+}
+
+impl std::error::Error for FromBytesError {}
@@ -1,8 +1,19 @@
-use core::fmt::Write;
+use core::{fmt::Write, ops::Bound};
 
 use crate::utf8_truncator;
 
-use super::{MicroStr, microstr};
+use super::{FromBytesError, MicroFmt, MicroStr, MicroStrList, MicroStrWriter, Trimmed, microstr, microstr_new, microstr_exact, microstr_join, to_hex, const_concat, valid_utf8_prefix_len, min_capacity_for, fits_in};
+
+/// Chars of every UTF-8 width, for property tests
+const MIXED_WIDTH: [char; 4] = ['a', 'ж', '€', '🦀'];
+
+/// Returns a seeded LCG, yielding deterministic numbers below the given bound
+fn random_below(mut seed: u32) -> impl FnMut(usize) -> usize {
+    move |bound| {
+        seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        (seed >> 8) as usize % bound
+    }
+}
 
 /* BASE METHODS */
 #[test]
@@ -12,6 +23,48 @@ fn new() {
     assert_eq!(s.len(), 0);
 }
 
+#[test]
+fn new_macro() {
+    const S: MicroStr<32> = microstr_new!(32);
+    assert_eq!(S.capacity(), 32);
+    assert!(S.is_empty());
+}
+
+#[test]
+fn exact_macro() {
+    const S: MicroStr<6> = microstr_exact!("Кот", 6);
+    assert_eq!(S.as_str(), "Кот");
+    let s = microstr_exact!("Кот", 10);
+    assert_eq!(s.extra_capacity(), 4);
+}
+
+#[test]
+fn join_macro() {
+    const PATH: MicroStr<14> = microstr_join!("/", "", "usr", "local", "bin");
+    const { assert!(PATH.capacity() == 14) };
+    assert_eq!(PATH.as_str(), "/usr/local/bin");
+
+    const KEY: MicroStr<14> = microstr_join!("::", "ключ", "a", 1);
+    assert_eq!(KEY.as_str(), "ключ::a::1");
+    assert_eq!(KEY.extra_capacity(), 0);
+
+    assert_eq!(microstr_join!(", ", "one").as_str(), "one");
+    assert!(microstr_join!(", ").is_empty());
+}
+
+#[test]
+fn capacity_helpers() {
+    const NAME: &str = "Данила";
+    const CAP: usize = min_capacity_for(NAME);
+    const { assert!(CAP == 12) };
+    const { assert!(fits_in::<CAP>(NAME)) };
+    const { assert!(!fits_in::<{ CAP - 1 }>(NAME)) };
+    const { assert!(fits_in::<0>("")) };
+
+    let s = microstr!(NAME);
+    assert_eq!(s.capacity(), CAP);
+}
+
 #[test]
 fn from_str() {
     let s = MicroStr::<15>::from_str("Hello, world").expect("Unreachable");
@@ -35,12 +88,81 @@ fn from_raw_buffer() {
     assert_eq!(s.as_str(), "Raw");
 }
 
+#[test]
+fn extend() {
+    let a = microstr!("ёж", 4);
+    let b = microstr!(", ", 2);
+    let c = microstr!("ёжик", 16);
+
+    // Each call takes one capacity, calls can mix them
+    let mut s = MicroStr::<16>::new();
+    s.extend([&a]);
+    s.extend(vec![b.clone()]);
+    s.extend(Some(&c));
+    assert_eq!(s.as_str(), "ёж, ёжик");
+
+    // Truncated and stopped at the first overflow
+    let mut s = microstr!(">", 8);
+    s.extend(vec![c.clone(), microstr!("!", 16)]);
+    assert_eq!(s.as_str(), ">ёжи");
+}
+
+#[test]
+fn from_other_cap() {
+    let small = microstr!("ёжик", 8);
+    let big: MicroStr<32> = (&small).into();
+    assert_eq!(big.as_str(), "ёжик");
+    assert_eq!(big.capacity(), 32);
+
+    let smaller = MicroStr::<5>::from(&big); // 'и' is not split
+    assert_eq!(smaller.as_str(), "ёж");
+    let same = MicroStr::<8>::from(&small);
+    assert_eq!(same, small);
+    let empty = MicroStr::<0>::from(&big);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn try_from_bytes() {
+    let s = MicroStr::<6>::try_from("Кот".as_bytes()).unwrap();
+    assert_eq!(s.as_str(), "Кот");
+    assert_eq!(s.extra_capacity(), 0);
+
+    assert_eq!(MicroStr::<5>::try_from("Кот".as_bytes()), Err(FromBytesError::Overflow));
+
+    let err = MicroStr::<6>::try_from(&b"ab\xffc"[..]).unwrap_err();
+    let FromBytesError::InvalidUtf8(err) = err else { panic!("expected UTF-8 error") };
+    assert_eq!(err.valid_up_to(), 2);
+}
+
 #[test]
 fn from_str_unchecked() {
     let s = unsafe { MicroStr::<15>::from_str_unchecked("Hello, world") };
     assert_eq!(s.as_str(), "Hello, world");
 }
 
+#[test]
+fn ascii() {
+    const { assert!(microstr!("Hello, world").is_ascii()) };
+    const { assert!(!microstr!("Привет, мир").is_ascii()) };
+
+    let s = MicroStr::<5>::try_from_ascii("Hello, world").unwrap();
+    assert_eq!(s.as_str(), "Hello");
+    assert!(s.is_ascii());
+    assert_eq!(MicroStr::<20>::try_from_ascii("Hello, мир"), Err(()));
+}
+
+#[test]
+fn from_ascii() {
+    const S: Option<MicroStr<8>> = MicroStr::from_ascii(b"GET /");
+    assert_eq!(S.unwrap().as_str(), "GET /");
+
+    assert_eq!(MicroStr::<4>::from_ascii(b"ABCDEF").unwrap().as_str(), "ABCD"); // Truncated
+    assert!(MicroStr::<4>::from_ascii(b"").unwrap().is_empty());
+    assert!(MicroStr::<16>::from_ascii("ёж".as_bytes()).is_none());
+    assert!(MicroStr::<4>::from_ascii(b"ABCD\xFF").is_none()); // Checked even past the capacity
+}
+
 #[test]
 fn pointers() {
     let mut s = microstr!("Hello, world!");
@@ -66,6 +188,8 @@ fn constants_and_variables() {
     let s = microstr!("Кот", 10);
 
     assert_eq!(s.capacity(), 10);
+    assert_eq!(s.byte_capacity(), 10);
+    assert_eq!(s.max_ascii_chars(), 10);
     assert_eq!(s.len(), 3);
     assert_eq!(s.bytes_len(), 6);
     assert_eq!(s.extra_capacity(), 4);
@@ -75,6 +199,100 @@ fn constants_and_variables() {
     assert!(s.is_empty());
 }
 
+#[test]
+fn char_count() {
+    const S: MicroStr<20> = microstr!("Кот 🐈 cat", 20);
+    const { assert!(S.char_count() == 9) };
+
+    for s in ["", "abc", "Привет, мир", "你好，世界", "🔥🦀❗️"] {
+        let s = MicroStr::<32>::from_const(s);
+        assert_eq!(s.char_count(), s.chars().count());
+    }
+}
+
+#[test]
+fn bytes_needed_for_chars() {
+    const S: MicroStr<16> = microstr!("Ёж🦀!", 16);
+    const { assert!(S.bytes_needed_for_chars(0) == 0) };
+    const { assert!(S.bytes_needed_for_chars(2) == 4) };
+    const { assert!(S.bytes_needed_for_chars(3) == 8) };
+    const { assert!(S.bytes_needed_for_chars(4) == 9) };
+    const { assert!(S.bytes_needed_for_chars(5) == 9) };
+
+    for n in 0..6 {
+        assert_eq!(S.bytes_needed_for_chars(n), S.chars().take(n).map(char::len_utf8).sum());
+    }
+    assert_eq!(MicroStr::<4>::new().bytes_needed_for_chars(1), 0);
+}
+
+#[test]
+fn byte_at() {
+    const S: MicroStr<8> = microstr!("aж", 8);
+    const { assert!(matches!(S.byte_at(0), Some(b'a'))) };
+    const { assert!(matches!(S.byte_at(2), Some(0xB6))) }; // Last byte of 'ж'
+    const { assert!(S.byte_at(3).is_none()) }; // Past the content, within the capacity
+    const { assert!(S.byte_at(100).is_none()) };
+
+    for (idx, &byte) in S.as_bytes().iter().enumerate() {
+        assert_eq!(S.byte_at(idx), Some(byte));
+    }
+}
+
+#[test]
+fn first_last_byte() {
+    const S: MicroStr<8> = microstr!("aж;", 8);
+    const { assert!(matches!(S.first_byte(), Some(b'a'))) };
+    const { assert!(matches!(S.last_byte(), Some(b';'))) };
+
+    const E: MicroStr<8> = microstr_new!(8);
+    const { assert!(E.first_byte().is_none()) };
+    const { assert!(E.last_byte().is_none()) };
+
+    assert_eq!(microstr!("ж").first_byte(), Some(0xD0));
+    assert_eq!(microstr!("ж").last_byte(), Some(0xB6));
+}
+
+#[test]
+fn char_at() {
+    let s = microstr!("Aß€🦀z");
+    for (idx, ch) in s.char_indices() {
+        assert_eq!(s.char_at(idx), Some(ch));
+        assert_eq!(unsafe { s.char_at_unchecked(idx) }, ch);
+    }
+    assert_eq!(s.char_at(2), None); // Inside 'ß'
+    assert_eq!(s.char_at(s.bytes_len()), None);
+
+    const S: MicroStr<2> = microstr!("ё");
+    const { assert!(matches!(S.char_at(0), Some('ё'))) };
+}
+
+#[test]
+fn can_push() {
+    const S: MicroStr<7> = microstr!("Кот", 7);
+    const { assert!(!S.can_push('🦀')) };
+    const { assert!(S.can_push('!')) };
+
+    let mut s = MicroStr::<4>::new();
+    assert!(s.can_push('🦀'));
+    s.push('a').unwrap();
+    assert!(!s.can_push('🦀'));
+}
+
+#[test]
+fn reserve_exact_check() {
+    let mut s = microstr!("abc", 6);
+    assert!(s.reserve_exact_check(0));
+    assert!(s.reserve_exact_check(3));
+    assert!(!s.reserve_exact_check(4));
+
+    assert_eq!(s.write_str("de"), Ok(()));
+    assert!(s.reserve_exact_check(1));
+    assert!(!s.reserve_exact_check(2));
+    assert_eq!(s.write_str("fg"), Err(core::fmt::Error));
+    assert_eq!(s.as_str(), "abcdef");
+    assert!(!s.reserve_exact_check(1));
+}
+
 #[test]
 fn push_char() {
     let mut s = MicroStr::<6>::new();
@@ -91,6 +309,18 @@ fn push_char() {
     assert_eq!(s.as_str(), "🦀");
 }
 
+#[test]
+fn append_char_n() {
+    let mut s = MicroStr::<6>::new();
+    assert_eq!(s.append_char_n('=', 10), 6);
+    assert_eq!(s.as_str(), "======");
+
+    let mut s = MicroStr::<10>::new();
+    assert_eq!(s.append_char_n('🦀', 10), 2);
+    assert_eq!(s.as_str(), "🦀🦀");
+    assert_eq!(s.append_char_n('x', 0), 0);
+}
+
 #[test]
 fn push_str() {
     let mut s = microstr!("Hello, ", 15);
@@ -100,6 +330,197 @@ fn push_str() {
     assert_eq!(s.as_str(), "Hello, world! N");
 }
 
+#[test]
+fn concat() {
+    const A: MicroStr<6> = microstr!("Hello,", 6);
+    const B: MicroStr<8> = microstr!(" мир", 8);
+    const FULL: MicroStr<16> = const_concat(&A, &B);
+    const TRUNCATED: MicroStr<10> = const_concat(&A, &B);
+    const ONLY_A: MicroStr<4> = const_concat(&A, &B);
+
+    assert_eq!(FULL.as_str(), "Hello, мир");
+    assert_eq!(TRUNCATED.as_str(), "Hello, м"); // 'и' is not split
+    assert_eq!(ONLY_A.as_str(), "Hell");
+}
+
+#[test]
+fn const_accessors() {
+    const S: MicroStr<16> = microstr!("Кот cat", 16);
+    const STR: &str = S.as_str();
+    const BYTES: &[u8] = S.as_bytes();
+    const { assert!(S.len() == 7) };
+    const { assert!(BYTES.len() == 10) };
+    assert_eq!(STR, "Кот cat");
+
+    const CAPITALIZED: MicroStr<16> = {
+        let mut s = S;
+        s.as_mut_bytes()[7] = b'C';
+        let _: &mut str = s.as_str_mut();
+        s
+    };
+    assert_eq!(CAPITALIZED.as_str(), "Кот Cat");
+}
+
+#[test]
+fn as_str_checked() {
+    let mut s = microstr!("Кот");
+    assert_eq!(s.as_str_checked(), Ok("Кот"));
+
+    unsafe { *s.as_mut_ptr().add(3) = b'!' }; // breaks 'о'
+    let err = s.as_str_checked().unwrap_err();
+    assert_eq!(err.valid_up_to(), 2);
+}
+
+#[test]
+fn write_str_counted() {
+    let mut s = MicroStr::<10>::new();
+    assert_eq!(s.write_str_counted("Кот"), 6);
+    assert_eq!(s.write_str_counted(""), 0);
+    assert_eq!(s.write_str_counted(" ёж"), 3);
+    assert_eq!(s.write_str_counted("!"), 1);
+    assert_eq!(s.write_str_counted("!"), 0);
+    assert_eq!(s.as_str(), "Кот ё!");
+}
+
+#[test]
+fn try_write_fmt() {
+    let mut s = MicroStr::<16>::new();
+    let (name, value) = ("темп", 21.5);
+    assert_eq!(s.try_write_fmt(format_args!("{name}={value}")), Ok(13));
+    assert_eq!(s.as_str(), "темп=21.5");
+
+    let mut s = MicroStr::<12>::new();
+    let (name, value) = ("температура", 21.5);
+    assert_eq!(s.try_write_fmt(format_args!("{name}={value}")), Err(12)); // 'у' is not split
+    assert_eq!(s.as_str(), "темпер");
+    assert_eq!(s.try_write_fmt(format_args!("{value}")), Err(0));
+
+    let mut s = MicroStr::<4>::new();
+    assert_eq!(s.try_write_fmt(format_args!("")), Ok(0));
+}
+
+#[test]
+fn append_display() {
+    let mut s = MicroStr::<10>::new();
+    assert_eq!(s.append_display(-273), Ok(()));
+    assert_eq!(s.append_display(' '), Ok(()));
+    assert_eq!(s.append_display(0.15), Ok(()));
+    assert_eq!(s.as_str(), "-273 0.15");
+
+    assert_eq!(s.append_display(3.25), Err(()));
+    assert_eq!(s.as_str(), "-273 0.153");
+
+    let mut s = MicroStr::<3>::new();
+    assert_eq!(s.append_display(microstr!("ёж")), Err(())); // 'ж' is not split
+    assert_eq!(s.as_str(), "ё");
+}
+
+#[test]
+fn push_bytes_iter() {
+    // Chars split across chunks of a stream
+    let chunks: [&[u8]; 3] = [b"a\xD0", b"\xB6\xF0\x9F", b"\xA6\x80"];
+    let mut s = MicroStr::<16>::new();
+    assert_eq!(s.push_bytes_iter(chunks.iter().flat_map(|c| c.iter().copied())), Ok(()));
+    assert_eq!(s.as_str(), "aж🦀");
+
+    // Invalid trailing byte
+    let mut s = MicroStr::<16>::new();
+    assert_eq!(s.push_bytes_iter(*b"ok\xC0"), Err(2));
+    assert_eq!(s.as_str(), "ok");
+
+    // Stray continuation byte
+    assert_eq!(s.push_bytes_iter(*b"!\x80?"), Err(1));
+    assert_eq!(s.as_str(), "ok!");
+
+    // Unfinished char at the end
+    assert_eq!(s.push_bytes_iter(*b"\xD0"), Err(0));
+    assert_eq!(s.as_str(), "ok!");
+
+    // Overflow
+    let mut s = MicroStr::<3>::new();
+    assert_eq!(s.push_bytes_iter("aжb".bytes()), Err(3));
+    assert_eq!(s.as_str(), "aж");
+}
+
+#[test]
+fn push_interleaved() {
+    let mut s = microstr!(">", 16);
+    assert_eq!(s.push_interleaved("ab", "ёж", ' '), Ok(()));
+    assert_eq!(s.as_str(), ">a ё b ж");
+
+    let mut s = MicroStr::<16>::new();
+    assert_eq!(s.push_interleaved("", "xyz", ','), Ok(()));
+    assert_eq!(s.as_str(), "x,y,z");
+
+    let mut s = MicroStr::<6>::new();
+    assert_eq!(s.push_interleaved("abc", "123", '-'), Err(5));
+    assert_eq!(s.as_str(), "a-1-b");
+
+    let mut s = MicroStr::<8>::new();
+    assert_eq!(s.push_interleaved("", "", '-'), Ok(()));
+    assert!(s.is_empty());
+}
+
+#[test]
+fn try_insert_str() {
+    let mut s = microstr!("a🦀b", 10);
+    assert_eq!(s.try_insert_str(1, "ж"), Ok(()));
+    assert_eq!(s.try_insert_str(4, "!"), Ok(())); // At the end
+    assert_eq!(s.try_insert_str(0, ""), Ok(()));
+    assert_eq!(s.as_str(), "aж🦀b!");
+
+    // No changes on failure
+    let before = s.clone();
+    assert_eq!(s.try_insert_str(6, "x"), Err(())); // Out of range
+    assert_eq!(s.try_insert_str(0, "ёж"), Err(())); // Only 1 byte left
+    assert!(s.eq_bytes(&before));
+    assert_eq!(s.try_insert_str(2, "x"), Ok(()));
+    assert_eq!(s.as_str(), "aжx🦀b!");
+}
+
+#[test]
+fn prepend() {
+    let mut s = microstr!("мир", 20);
+    assert_eq!(s.prepend("Привет, "), Ok(()));
+    assert_eq!(s.as_str(), "Привет, мир");
+    assert_eq!(s.prepend(""), Ok(()));
+    assert_eq!(s.as_str(), "Привет, мир");
+
+    // Tail is cut, 'р' is not split
+    let mut s = microstr!("мир", 7);
+    assert_eq!(s.prepend("> "), Err(6));
+    assert_eq!(s.as_str(), "> ми");
+
+    // Prefix alone overflows, existing content is dropped
+    let mut s = microstr!("abc", 5);
+    assert_eq!(s.prepend("Привет"), Err(4));
+    assert_eq!(s.as_str(), "Пр");
+    let mut s = MicroStr::<5>::new();
+    assert_eq!(s.prepend("Привет"), Err(4));
+    assert_eq!(s.as_str(), "Пр");
+
+    // Prefix fills the capacity exactly
+    let mut s = microstr!("abc", 4);
+    assert_eq!(s.prepend("Пр"), Err(4)); // `n == s.len()`, so `s` is whole
+    assert_eq!(s.as_str(), "Пр");
+}
+
+#[test]
+fn push_line() {
+    let mut s = MicroStr::<13>::new();
+    assert_eq!(s.push_line("раз"), Ok(()));
+    assert_eq!(s.as_str(), "раз\n");
+
+    // Line fits, newline doesn't
+    assert_eq!(s.push_line("два"), Err(6));
+    assert_eq!(s.as_str(), "раз\nдва");
+
+    // Line is truncated
+    let mut s = MicroStr::<5>::new();
+    assert_eq!(s.push_line("три"), Err(4));
+    assert_eq!(s.as_str(), "тр");
+}
+
 #[test]
 fn bytes() {
     let mut s = microstr!("Rust?", 10);
@@ -109,117 +530,1651 @@ fn bytes() {
 }
 
 #[test]
-fn into_raw_buffer() {
-    let s = microstr!("RAW", 4);
-    let buf = s.into_raw_buffer();
+fn utf16() {
+    let s = microstr!("a😀b🦀");
+    let mut out = [0u16; 6];
+    assert_eq!(s.encode_utf16_into(&mut out), 6);
+    assert_eq!(out, [0x61, 0xD83D, 0xDE00, 0x62, 0xD83E, 0xDD80]);
 
-    assert_eq!(buf, [b'R', b'A', b'W', 0]);
+    let mut short = [0u16; 5];
+    assert_eq!(s.encode_utf16_into(&mut short), 4); // surrogate pair is not split
+    assert_eq!(short[..4], out[..4]);
+
+    let back = MicroStr::<10>::from_utf16(&out).unwrap();
+    assert_eq!(back, s);
+    let truncated = MicroStr::<6>::from_utf16(&out).unwrap();
+    assert_eq!(truncated.as_str(), "a😀b");
+    assert!(MicroStr::<10>::from_utf16(&[0x61, 0xDE00]).is_err());
 }
 
 #[test]
-fn clear() {
-    let mut s = microstr!("Dαηίlα Mίητ");
-    s.clear();
-    assert_eq!(s.as_str(), "");
-    assert_eq!(s.len(), 0);
+fn hex() {
+    let bytes = [0x00, 0x7f, 0x80, 0xff, 0x42];
+    let s: MicroStr<10> = to_hex(&bytes);
+    assert_eq!(s.as_str(), "007f80ff42");
+    assert_eq!(s.from_hex(), Ok(bytes));
+
+    let s: MicroStr<5> = to_hex(&bytes);
+    assert_eq!(s.as_str(), "007f");
+    assert_eq!(s.from_hex::<2>(), Ok([0x00, 0x7f]));
+
+    assert_eq!(microstr!("0g").from_hex::<1>(), Err(()));
+    assert_eq!(microstr!("abc").from_hex::<2>(), Err(()));
 }
 
 #[test]
-fn truncate() {
-    let mut s = microstr!("Номер 1234567890");
-    s.truncate(11);
-    assert_eq!(s.as_str(), "Номер 12345");
+#[cfg(feature = "base64")]
+fn base64() {
+    use crate::base64_encode;
+
+    assert_eq!(base64_encode::<8>(b"").as_str(), "");
+    assert_eq!(base64_encode::<8>(b"f").as_str(), "Zg==");
+    assert_eq!(base64_encode::<8>(b"fo").as_str(), "Zm8=");
+    assert_eq!(base64_encode::<8>(b"foo").as_str(), "Zm9v");
+    assert_eq!(base64_encode::<8>(b"foob").as_str(), "Zm9vYg==");
+    assert_eq!(base64_encode::<12>(b"foobar").as_str(), "Zm9vYmFy");
+    assert_eq!(base64_encode::<7>(b"foobar").as_str(), "Zm9v"); // Truncated
+    assert_eq!(base64_encode::<32>(b"Aladdin:open sesame").as_str(), "QWxhZGRpbjpvcGVuIHNlc2FtZQ==");
 }
 
 #[test]
-fn default() {
-    let s: MicroStr<10> = MicroStr::default();
+fn as_mut_array() {
+    let mut s = microstr!("old", 8);
+    unsafe {
+        let array = s.as_mut_array();
+        assert_eq!(array.len(), 8);
+        array[..4].copy_from_slice("ёж".as_bytes());
+        array[4] = b'!';
+        s.set_len(5);
+    }
+    assert_eq!(s.as_str(), "ёж!");
+}
+
+#[test]
+fn index_byte() {
+    let s = microstr!("aж!", 8);
+    assert_eq!(s[0], b'a');
+    assert_eq!([s[1], s[2]], *"ж".as_bytes());
+    assert_eq!(s[3], b'!');
+    // Ranges still slice the str
+    assert_eq!(&s[1..3], "ж");
+    assert_eq!(&s[1..], "ж!");
+    assert_eq!(&s[..1], "a");
+    assert_eq!(&s[..], "aж!");
+    assert_eq!(&s[1..=2], "ж");
+    assert_eq!(&s[..=2], "aж");
+    assert_eq!(&s[(Bound::Included(1), Bound::Unbounded)], "ж!");
+    assert_eq!(&s[(Bound::Excluded(0), Bound::Excluded(3))], "ж");
+    let mut m = s.clone();
+    m[..1].make_ascii_uppercase();
+    assert_eq!(m.as_str(), "Aж!");
+
+    let result = std::panic::catch_unwind(|| s[4]); // Past the content, not the capacity
+    assert!(result.is_err());
+}
+
+#[test]
+fn as_ptr_range() {
+    let s = microstr!("ёж!", 16);
+    let range = s.as_ptr_range();
+    assert_eq!(range.start, s.as_ptr());
+    assert_eq!(range.end as usize - range.start as usize, s.bytes_len());
+
+    let empty = MicroStr::<4>::new();
+    let range = empty.as_ptr_range();
+    assert_eq!(range.start, range.end);
+}
+
+#[test]
+fn bytes_mut_guard() {
+    let mut s = microstr!("hello мир");
+    {
+        let mut bytes = s.bytes_mut_guard();
+        assert_eq!(bytes.len(), 12);
+        bytes[..5].make_ascii_uppercase();
+    }
+    assert_eq!(s.as_str(), "HELLO мир");
+
+    // Orphaned continuation byte cuts the content
+    s.bytes_mut_guard()[8] = b'!'; // First byte of 'и'
+    assert_eq!(s.as_str(), "HELLO м!");
+
+    // Unfinished char at the end is cut off
+    s.bytes_mut_guard()[8] = 0xD0;
+    assert_eq!(s.as_str(), "HELLO м");
+
+    s.bytes_mut_guard()[7] = b'x'; // Second byte of 'м'
+    assert_eq!(s.as_str(), "HELLO ");
+}
+
+#[test]
+fn bytes_mut_guard_leaked() {
+    let mut s = microstr!("abc");
+    let mut bytes = s.bytes_mut_guard();
+    bytes[0] = 0xFF;
+    core::mem::forget(bytes);
     assert_eq!(s.as_str(), "");
-    assert_eq!(s.len(), 0);
+    assert!(core::str::from_utf8(s.as_str().as_bytes()).is_ok());
 }
 
 #[test]
-fn compare() {
-    let s1 = microstr!("hello", 5);
-    let s2 = microstr!("hello", 10);
-    let s3 = microstr!("world", 5);
+fn into_raw_buffer() {
+    let s = microstr!("RAW", 4);
+    let buf = s.into_raw_buffer();
 
-    assert_eq!(s1, s2);
-    assert_ne!(s1, s3);
-    assert_ne!(s2, s3);
+    assert_eq!(buf, [b'R', b'A', b'W', 0]);
 }
 
 #[test]
-fn deref() {
-    let s = microstr!("Hello", 15);
+fn copy_to_slice() {
+    let s = microstr!("id=ёж");
 
-    assert!( s.is_ascii() );
-    assert_eq!(s.to_ascii_uppercase(), "HELLO");
+    let mut frame = [b'.'; 12];
+    assert_eq!(s.copy_to_slice(&mut frame[2..]), 7);
+    assert_eq!(&frame, b"..id=\xD1\x91\xD0\xB6...");
+
+    let mut small = [0u8; 6];
+    assert_eq!(s.copy_to_slice(&mut small), 5); // 'ж' is not split
+    assert_eq!(&small[..5], "id=ё".as_bytes());
+
+    assert_eq!(s.copy_to_slice(&mut []), 0);
 }
 
 #[test]
-fn fmt() {
-    let mut s = microstr!("", 50);
-    assert_eq!(s.write_char('a'), Ok(()));
-    assert_eq!(s.write_str("bcdef"), Ok(()));
-    assert_eq!(s.write_fmt(format_args!("; {} = {}", "var", 10)), Ok(()));
+fn clone_into_cap() {
+    let s = microstr!("ёжик", 8);
 
-    assert_eq!(s.as_str(), "abcdef; var = 10");
+    let mut big = microstr!("previous content", 16);
+    s.clone_into_cap(&mut big);
+    assert_eq!(big.as_str(), "ёжик");
+
+    let mut small = MicroStr::<5>::new();
+    s.clone_into_cap(&mut small);
+    assert_eq!(small.as_str(), "ёж"); // Truncated, 'и' doesn't fit in the last byte
+
+    microstr!("", 1).clone_into_cap(&mut big);
+    assert!(big.is_empty());
 }
 
 #[test]
-fn truncator() {
-    let s = "Hello, world";
-    assert_eq!(utf8_truncator(s, 0), 0);    // ""
-    assert_eq!(utf8_truncator(s, 20), 12);  // "Hello, world"
-    assert_eq!(utf8_truncator(s, 10), 10);  // "Hello, wor"
+fn contains_str() {
+    const S: MicroStr<32> = microstr!("Привет, мир", 32);
+    const { assert!(S.contains_str("вет")) };
+    const { assert!(S.contains_str("")) };
+    const { assert!(!S.contains_str("Мир")) };
+    const { assert!(!S.contains_str("Привет, мир!")) }; // longer than content
 
-    let s = "Привет, мир";
-    assert_eq!(utf8_truncator(s, 10), 10);  // "Приве"
-    assert_eq!(utf8_truncator(s, 11), 10);  // "Приве"
-    assert_eq!(utf8_truncator(s, 12), 12);  // "Привет"
-    assert_eq!(utf8_truncator(s, 13), 13);  // "Привет,"
+    // Bytes past `len` are not searched
+    let mut s = microstr!("abcdef");
+    s.truncate(3);
+    assert!(!s.contains_str("cd"));
+    assert!(s.contains_str("abc"));
+}
 
-    let s = "你好，世界";
-    assert_eq!(utf8_truncator(s, 3), 3);  // "你"
-    assert_eq!(utf8_truncator(s, 4), 3);  // "你"
-    assert_eq!(utf8_truncator(s, 5), 3);  // "你"
-    assert_eq!(utf8_truncator(s, 6), 6);  // "你好"
+#[test]
+fn with_bytes() {
+    const S: MicroStr<16> = microstr!("\x1b[1mКот", 16);
+    const { assert!(S.starts_with_bytes(b"\x1b[")) };
+    const { assert!(S.ends_with_bytes("т".as_bytes())) };
 
-    let s = "🔥🦀❗️";
-    assert_eq!(utf8_truncator(s, 3), 0);  // ""
-    assert_eq!(utf8_truncator(s, 4), 4);  // "🔥"
-    assert_eq!(utf8_truncator(s, 5), 4);  // "🔥"
-    assert_eq!(utf8_truncator(s, 6), 4);  // "🔥"
-    assert_eq!(utf8_truncator(s, 7), 4);  // "🔥"
-    assert_eq!(utf8_truncator(s, 8), 8);  // "🔥🦀"
+    assert!(S.starts_with_bytes(b""));
+    assert!(S.ends_with_bytes(b""));
+    assert!(!S.starts_with_bytes(b"[1m"));
+    assert!(!S.ends_with_bytes(&[0xd1, 0x82, 0x00]));
+    assert!(!microstr!("ab").starts_with_bytes(b"abc"));
+    assert!(!microstr!("ab").ends_with_bytes(b"abc"));
 }
 
-/* STD ONLY */
+#[test]
+fn count_and_position() {
+    let is_vowel = |c: char| "aeiouаеёиоуыэюя".contains(c);
+    let s = microstr!("Hello, Привет");
+    assert_eq!(s.count_chars_if(is_vowel), 4);
+    assert_eq!(s.position_of_nth(0, is_vowel), Some(1));
+    assert_eq!(s.position_of_nth(2, is_vowel), Some(9));
+    assert_eq!(s.position_of_nth(4, is_vowel), None);
+
+    let s = microstr!("Ёлка №12, шар №7");
+    assert_eq!(s.count_chars_if(|c| c.is_ascii_digit()), 3);
+    assert_eq!(s.position_of_nth(1, |c| c.is_ascii_digit()), Some(7));
+}
 
 #[test]
-fn output() {
-    let s = microstr!("Some Output", 25);
-    assert_eq!(format!("{:?}", s), "MicroStr<25>{\"Some Output\"}");
-    assert_eq!(format!("{}", s), "Some Output");
+fn count_matches() {
+    let s = microstr!("ёж ёжик ёёё");
+    assert_eq!(s.count_char_matches('ё'), 5);
+    assert_eq!(s.count_char_matches(' '), 2);
+    assert_eq!(s.count_char_matches('е'), 0); // Not 'ё'
+
+    assert_eq!(s.count_str_matches("ёж"), 2);
+    assert_eq!(s.count_str_matches("ёё"), 1); // Non-overlapping
+    assert_eq!(s.count_str_matches("ёжики"), 0);
+    assert_eq!(s.count_str_matches(""), s.char_count() + 1);
+
+    let mut s = microstr!("abab");
+    s.truncate(3);
+    assert_eq!(s.count_char_matches('b'), 1); // Bytes past `len` are not counted
+    assert_eq!(s.count_str_matches("ab"), 1);
 }
 
 #[test]
-fn string() {
-    let string = String::from("Heap Allocated!");
+fn line_count() {
+    const { assert!(microstr!("a\nb").line_count() == 2) };
+    for text in ["", "\n", "\n\n", "one", "one\n", "one\ntwo", "one\ntwo\n", "one\r\ntwo\r\n", "\nж\n\n🦀"] {
+        assert_eq!(MicroStr::<16>::from_const(text).line_count(), text.lines().count(), "{text:?}");
+    }
 
-    let s = MicroStr::<20>::from(string);
+    let mut s = microstr!("a\nb\n");
+    s.truncate(2);
+    assert_eq!(s.line_count(), 1); // Bytes past `len` are not counted
+}
 
-    assert_eq!(s.as_str(), "Heap Allocated!");
+#[test]
+fn match_positions() {
+    let s = microstr!("ёлка, ёж, ёрш, ёмкость");
+    assert_eq!(s.match_positions::<8>('ё'), ([0, 6, 10, 15, 0, 0, 0, 0], 4));
+    assert_eq!(s.match_positions::<2>('ё'), ([0, 6], 4));
+    assert_eq!(s.match_positions::<0>('ё'), ([], 4));
+    assert_eq!(s.match_positions::<2>('!'), ([0, 0], 0));
+}
 
-    let return_string = String::from(s);
+#[test]
+fn is_blank() {
+    assert!(MicroStr::<4>::new().is_blank());
+    assert!(microstr!("   ").is_blank());
+    assert!(microstr!("\t\r\n\u{3000}").is_blank()); // Unicode whitespace too
+    assert!(!microstr!("  a ").is_blank());
+    assert!(!microstr!("\0").is_blank());
 
-    assert_eq!(return_string, "Heap Allocated!");
+    let mut s = microstr!(" x");
+    s.truncate(1);
+    assert!(s.is_blank());
 }
 
 #[test]
-#[cfg(feature = "serde")]
-fn serde() {
-    let string = microstr!("{\"key\": 42}");
-    string.to_json();
+fn is_blank_const() {
+    const { assert!(microstr!("\t\r\n\u{3000}").is_blank_const()) };
+    assert!(MicroStr::<4>::new().is_blank_const());
+    assert!(!microstr!("  a ").is_blank_const());
+    assert!(!microstr!("\0").is_blank_const());
+
+    // Agrees with `is_blank` on every char
+    for c in '\0'..=char::MAX {
+        let s = MicroStr::<4>::from_chars([c]);
+        assert_eq!(s.is_blank_const(), s.is_blank(), "{c:?}");
+    }
+}
+
+#[test]
+fn has_content() {
+    const { assert!(microstr!("a").has_content()) };
+    assert!(!MicroStr::<4>::new().has_content());
+}
+
+#[test]
+fn char_predicates() {
+    let is_ident = |s: &MicroStr<16>| {
+        s.chars().next().is_some_and(|c| c == '_' || c.is_alphabetic())
+            && s.all_chars(|c| c == '_' || c.is_alphanumeric())
+    };
+    assert!(is_ident(&microstr!("_private", 16)));
+    assert!(is_ident(&microstr!("имя2", 16)));
+    assert!(!is_ident(&microstr!("2fast", 16)));
+    assert!(!is_ident(&microstr!("with-dash", 16)));
+    assert!(!is_ident(&microstr!("", 16)));
+
+    assert!(microstr!("a b").any_char(|c| c == ' '));
+    assert!(!microstr!("ab").any_char(|c| c == ' '));
+
+    assert!(microstr!("Abc123").is_alphanumeric_ascii());
+    assert!(!microstr!("Ab_c").is_alphanumeric_ascii());
+    assert!(!microstr!("имя").is_alphanumeric_ascii());
+    assert!(microstr!("").is_alphanumeric_ascii());
+}
+
+#[test]
+fn partition_point() {
+    let s = microstr!("12345абв");
+    assert_eq!(s.partition_point(|c| c.is_ascii_digit()), 5);
+    assert_eq!(s.partition_point(|c| c < '4'), 3);
+    assert_eq!(s.partition_point(|_| true), 8);
+    assert_eq!(s.partition_point(|c| c.is_alphabetic()), 0);
+    assert_eq!(MicroStr::<4>::new().partition_point(|_| true), 0);
+}
+
+#[test]
+fn char_boundaries() {
+    let s = microstr!("Ok, 世界🌍!");
+    assert!(s.char_boundaries().eq([0, 1, 2, 3, 4, 7, 10, 14, 15]));
+    assert!(s.char_boundaries().all(|idx| s.is_char_boundary(idx)));
+    assert!(MicroStr::<4>::new().char_boundaries().eq([0]));
+}
+
+#[test]
+fn split_at_byte() {
+    let s = microstr!("GET /путь");
+    assert_eq!(s.split_at_byte(0), Some(("", "GET /путь")));
+    assert_eq!(s.split_at_byte(3), Some(("GET", " /путь")));
+    assert_eq!(s.split_at_byte(7), Some(("GET /п", "уть")));
+    assert_eq!(s.split_at_byte(13), Some(("GET /путь", "")));
+
+    assert_eq!(s.split_at_byte(6), None); // Inside 'п'
+    assert_eq!(s.split_at_byte(14), None); // Past the end
+}
+
+#[test]
+fn split_last_char() {
+    let mut s = microstr!("aж€🦀");
+    let mut peeled = vec![];
+    while let Some((rest, last)) = s.split_last_char() {
+        peeled.push(last);
+        s = MicroStr::from_const(rest);
+    }
+    assert_eq!(peeled, ['🦀', '€', 'ж', 'a']);
+    assert_eq!(s.split_last_char(), None);
+
+    assert_eq!(microstr!("ё").split_last_char(), Some(("", 'ё')));
+}
+
+#[test]
+fn split_once_char() {
+    let s = microstr!("key=value=extra");
+    assert_eq!(s.split_once_char('='), Some(("key", "value=extra")));
+    assert_eq!(s.rsplit_once_char('='), Some(("key=value", "extra")));
+    assert_eq!(s.split_once_char('#'), None);
+    assert_eq!(s.rsplit_once_char('#'), None);
+
+    let s = microstr!("ключ→значение→ещё");
+    assert_eq!(s.split_once_char('→'), Some(("ключ", "значение→ещё")));
+    assert_eq!(s.rsplit_once_char('→'), Some(("ключ→значение", "ещё")));
+}
+
+#[test]
+fn split_terminator_into() {
+    let s = microstr!("a;b;");
+    let (parts, count) = s.split_terminator_into::<3>(';');
+    assert_eq!(count, 2);
+    assert_eq!([parts[0].as_str(), parts[1].as_str(), parts[2].as_str()], ["a", "b", ""]);
+
+    let s = microstr!("ключ;;значение");
+    let (parts, count) = s.split_terminator_into::<2>(';');
+    assert_eq!(count, 2);
+    assert_eq!([parts[0].as_str(), parts[1].as_str()], ["ключ", ""]);
+
+    let (_, count) = MicroStr::<4>::new().split_terminator_into::<2>(';');
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn rsplit_into() {
+    let s = microstr!("a.b.c");
+    let (parts, count) = s.rsplit_into::<4>('.');
+    assert_eq!(count, 3);
+    assert_eq!([parts[0].as_str(), parts[1].as_str(), parts[2].as_str(), parts[3].as_str()], ["c", "b", "a", ""]);
+
+    let (parts, count) = s.rsplit_into::<2>('.');
+    assert_eq!(count, 2);
+    assert_eq!([parts[0].as_str(), parts[1].as_str()], ["c", "b"]);
+
+    let (parts, count) = microstr!("a.").rsplit_into::<2>('.');
+    assert_eq!(count, 2);
+    assert_eq!([parts[0].as_str(), parts[1].as_str()], ["", "a"]);
+
+    let (parts, count) = MicroStr::<4>::new().rsplit_into::<2>('.');
+    assert_eq!(count, 1);
+    assert_eq!(parts[0].as_str(), "");
+}
+
+#[test]
+fn map_chars() {
+    let rot13 = |c: char| match c {
+        'a'..='z' => (((c as u8 - b'a') + 13) % 26 + b'a') as char,
+        'A'..='Z' => (((c as u8 - b'A') + 13) % 26 + b'A') as char,
+        _ => c,
+    };
+    let s = microstr!("Hello, World!");
+    assert_eq!(s.map_chars(rot13).as_str(), "Uryyb, Jbeyq!");
+    assert_eq!(s.map_chars(rot13).map_chars(rot13), s);
+
+    let s = microstr!("abcd", 6);
+    let wide = s.map_chars(|c| if c == 'b' { '🦀' } else { c });
+    assert_eq!(wide.as_str(), "a🦀c"); // 'd' does not fit
+}
+
+#[test]
+fn filter_chars() {
+    let s = microstr!("Тел: +7 (912) 345-67-89");
+    assert_eq!(s.filter_chars(|c| !c.is_ascii_digit()).as_str(), "Тел: + () --");
+    assert_eq!(s.filter_chars(|c| c.is_ascii_digit()).as_str(), "79123456789");
+    assert_eq!(s.filter_chars(|_| false).as_str(), "");
+}
+
+#[test]
+#[cfg(feature = "unicode-segmentation")]
+fn graphemes() {
+    let s = microstr!("cafe\u{301}!");
+    assert_eq!(s.len(), 6);
+    assert_eq!(s.grapheme_len(), 5);
+    assert!(s.graphemes().eq(["c", "a", "f", "e\u{301}", "!"]));
+
+    let family = microstr!("👨‍👩‍👧 ok");
+    assert_eq!(family.grapheme_len(), 4);
+    assert_eq!(family.graphemes().next(), Some("👨‍👩‍👧"));
+}
+
+#[test]
+#[cfg(feature = "unicode-segmentation")]
+fn truncate_graphemes() {
+    let mut s = microstr!("cafe\u{301}!");
+    s.truncate_graphemes(4);
+    assert_eq!(s.as_str(), "cafe\u{301}");
+    s.truncate_graphemes(10);
+    assert_eq!(s.as_str(), "cafe\u{301}");
+
+    let mut flags = microstr!("🇷🇺🇺🇸🇯🇵");
+    flags.truncate_graphemes(2);
+    assert_eq!(flags.as_str(), "🇷🇺🇺🇸");
+    flags.truncate_graphemes(0);
+    assert!(flags.is_empty());
+}
+
+#[test]
+fn clear() {
+    let mut s = microstr!("Dαηίlα Mίητ");
+    s.clear();
+    assert_eq!(s.as_str(), "");
+    assert_eq!(s.len(), 0);
+    assert!(s.is_empty());
+    assert!(!s.has_content());
+}
+
+#[test]
+fn take() {
+    let mut s = microstr!("Состояние", 20);
+    let taken = s.take();
+    assert_eq!(taken.as_str(), "Состояние");
+    assert_eq!(taken.capacity(), 20);
+    assert!(s.is_empty());
+    assert_eq!(s.as_str(), "");
+    assert!(s.take().is_empty());
+}
+
+#[test]
+fn move_bytes() {
+    // Remove 'ь' from the middle
+    let mut s = microstr!("медьведь");
+    unsafe {
+        s.move_bytes(8, 6, 8);
+        s.set_len(14);
+    }
+    assert_eq!(s.as_str(), "медведь");
+
+    // Insert "!" into the middle, overlapping ranges
+    let mut s = microstr!("ab🦀", 8);
+    unsafe {
+        s.move_bytes(1, 2, 5);
+        *s.as_mut_ptr().add(1) = b'!';
+        s.set_len(7);
+    }
+    assert_eq!(s.as_str(), "a!b🦀");
+}
+
+#[test]
+fn move_bytes_aliasing() {
+    // Meant for `cargo miri test`: the source and destination pointers
+    // must stay valid together, in both directions
+    let mut s = microstr!("0123456789");
+    unsafe {
+        s.move_bytes(2, 0, 8);
+        s.move_bytes(0, 2, 8);
+    }
+    assert_eq!(s.as_str(), "2323456789");
+
+    // Callers closing a gap through it
+    let mut s = microstr!("--ёж--");
+    s.trim_matches_char('-');
+    assert_eq!(s.as_str(), "ёж");
+    s.marquee_step();
+    assert_eq!(s.as_str(), "жё");
+}
+
+#[test]
+fn fix_char_boundary() {
+    let mut s = microstr!("a🦀", 8);
+    for (bad_len, fixed) in [(5, "a🦀"), (4, "a"), (3, "a"), (2, "a"), (1, "a"), (0, "")] {
+        unsafe {
+            s.set_len(bad_len);
+            s.fix_char_boundary();
+        }
+        assert_eq!(s.as_str(), fixed);
+    }
+
+    let mut s = MicroStr::<8>::new();
+    unsafe {
+        s.as_mut_ptr().copy_from_nonoverlapping("ёж".as_ptr(), 3);
+        s.set_len(3);
+        s.fix_char_boundary();
+    }
+    assert_eq!(s.as_str(), "ё");
+}
+
+#[test]
+fn truncate_to_own_length() {
+    // Full buffer: the terminating zero must not be written past `CAP`
+    let mut s = microstr!("ёжик");
+    s.truncate(4);
+    assert_eq!(s.as_str(), "ёжик");
+    s.truncate(100);
+    assert_eq!(s.as_str(), "ёжик");
+
+    let mut s = microstr!("ab", 8);
+    s.truncate(2);
+    assert_eq!(s.as_str(), "ab");
+}
+
+#[test]
+fn truncate() {
+    let mut s = microstr!("Номер 1234567890");
+    s.truncate(11);
+    assert_eq!(s.as_str(), "Номер 12345");
+    s.truncate(0);
+    assert!(s.is_empty());
+    assert!(!s.has_content());
+    assert_eq!(s.char_count(), 0);
+}
+
+#[test]
+fn truncate_bytes() {
+    const S: MicroStr<16> = microstr!("aж🦀b", 16);
+    let expected = [(0, ""), (1, "a"), (2, "a"), (3, "aж"), (6, "aж"), (7, "aж🦀"), (8, "aж🦀b"), (100, "aж🦀b")];
+    for (byte_len, result) in expected {
+        let mut s = S.clone();
+        s.truncate_bytes_floor(byte_len);
+        assert_eq!(s.as_str(), result);
+    }
+
+    for byte_len in [0, 1, 3, 7, 8, 100] {
+        let mut s = S.clone();
+        s.truncate_bytes_exact(byte_len);
+        assert_eq!(s.bytes_len(), byte_len.min(8));
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "`byte_len` is not on a char boundary")]
+fn truncate_bytes_exact_inside_char() {
+    microstr!("aж🦀b").truncate_bytes_exact(5);
+}
+
+#[test]
+fn truncate_ellipsis() {
+    // Just over the limit
+    let mut s = microstr!("abcdef", 16);
+    s.truncate_ellipsis(5);
+    assert_eq!(s.as_str(), "abcd…");
+
+    // Well over the limit
+    let mut s = microstr!("Очень длинная строка", 64);
+    s.truncate_ellipsis(6);
+    assert_eq!(s.as_str(), "Очень…");
+
+    // Within the limit
+    let mut s = microstr!("short");
+    s.truncate_ellipsis(5);
+    assert_eq!(s.as_str(), "short");
+
+    // The ellipsis doesn't fit after truncation, more chars are removed
+    let mut s = microstr!("abcdefgh", 8);
+    s.truncate_ellipsis(7);
+    assert_eq!(s.as_str(), "abcde…");
+    let mut s = microstr!("жжab", 6);
+    s.truncate_ellipsis(3);
+    assert_eq!(s.as_str(), "ж…"); // 'ж' is not split
+
+    // Edge cases
+    let mut s = microstr!("abc");
+    s.truncate_ellipsis(0);
+    assert_eq!(s.as_str(), "");
+    let mut s = microstr!("ab", 2);
+    s.truncate_ellipsis(1);
+    assert_eq!(s.as_str(), "a"); // Too small for the ellipsis
+}
+
+#[test]
+fn replace_tail() {
+    let mut s = microstr!("Кадр 9", 12);
+    assert_eq!(s.replace_tail(5, "10"), Ok(()));
+    assert_eq!(s.as_str(), "Кадр 10");
+    assert_eq!(s.replace_tail(5, "100"), Ok(()));
+    assert_eq!(s.as_str(), "Кадр 100");
+    assert_eq!(s.replace_tail(5, "1000"), Err(3));
+    assert_eq!(s.as_str(), "Кадр 100");
+
+    // Full buffer, nothing to cut
+    let mut s = microstr!("abc");
+    assert_eq!(s.replace_tail(3, ""), Ok(()));
+    assert_eq!(s.replace_tail(10, "d"), Err(0));
+    assert_eq!(s.as_str(), "abc");
+}
+
+#[test]
+fn trim_matches_char() {
+    let mut s = microstr!("\"value\"");
+    s.trim_matches_char('"');
+    assert_eq!(s.as_str(), "value");
+    s.trim_matches_char('"');
+    assert_eq!(s.as_str(), "value");
+
+    let mut s = microstr!("——тире——");
+    s.trim_matches_char('—');
+    assert_eq!(s.as_str(), "тире");
+
+    let mut s = microstr!("***");
+    s.trim_matches_char('*');
+    assert!(s.is_empty());
+}
+
+#[test]
+fn delete_byte_range() {
+    let mut s = microstr!("aж🦀b");
+    s.delete_byte_range(1, 7);
+    assert_eq!(s.as_str(), "ab");
+    s.delete_byte_range(2, 2);
+    assert_eq!(s.as_str(), "ab");
+    s.delete_byte_range(0, 2);
+    assert!(s.is_empty());
+
+    // Random deletions agree with `String::replace_range`
+    let mut next = random_below(0x2545_F491);
+    let alphabet = MIXED_WIDTH;
+    for _ in 0..500 {
+        let mut s = MicroStr::<32>::new();
+        while s.push(alphabet[next(alphabet.len())]).is_ok() {}
+        let mut expected = String::from(s.as_str());
+        for _ in 0..4 {
+            let bounds: Vec<usize> = expected.char_indices().map(|(i, _)| i).chain([expected.len()]).collect();
+            let a = bounds[next(bounds.len())];
+            let b = bounds[next(bounds.len())];
+            let (start, end) = (a.min(b), a.max(b));
+            s.delete_byte_range(start, end);
+            expected.replace_range(start..end, "");
+            assert_eq!(s.as_str(), expected);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "byte range is not on char boundaries")]
+fn delete_byte_range_inside_char() {
+    microstr!("aжb").delete_byte_range(0, 2);
+}
+
+#[test]
+#[should_panic(expected = "byte range is out of the content")]
+fn delete_byte_range_past_len() {
+    microstr!("ab", 8).delete_byte_range(1, 3);
+}
+
+#[test]
+fn pad_center() {
+    let mut s = microstr!("hi", 10);
+    s.pad_center(6, ' ');
+    assert_eq!(s.as_str(), "  hi  ");
+    s.pad_center(3, ' ');
+    assert_eq!(s.as_str(), "  hi  ");
+
+    let mut s = microstr!("ок", 10);
+    s.pad_center(5, '·'); // '·' is 2 bytes, only 3 fit
+    assert_eq!(s.as_str(), "·ок··");
+
+    let mut s = microstr!("ок", 8);
+    s.pad_center(6, '·'); // only 2 fit
+    assert_eq!(s.as_str(), "·ок·");
+}
+
+#[test]
+fn map_ascii_bytes() {
+    let mut s = microstr!("Card 4111-1111, срок 12/29");
+    s.map_ascii_bytes(|b| if b.is_ascii_digit() { b'*' } else { b });
+    assert_eq!(s.as_str(), "Card ****-****, срок **/**");
+
+    // Non-ASCII results are ignored
+    let mut s = microstr!("aбc");
+    s.map_ascii_bytes(|_| 0xd0);
+    assert_eq!(s.as_str(), "aбc");
+}
+
+#[test]
+fn overwrite_at() {
+    let mut s = microstr!("id=abc;ж");
+    assert_eq!(s.overwrite_at(3, "xyz"), Ok(()));
+    assert_eq!(s.as_str(), "id=xyz;ж");
+    assert_eq!(s.overwrite_at(3, "ёa"), Ok(())); // Same bytes, fewer chars
+    assert_eq!(s.as_str(), "id=ёa;ж");
+
+    assert_eq!(s.overwrite_at(6, "a"), Err(())); // Ends inside 'ж'
+    assert_eq!(s.overwrite_at(6, "abc"), Err(())); // Past the end
+    assert_eq!(s.overwrite_at(8, ""), Err(())); // Out of range
+    assert_eq!(s.as_str(), "id=ёa;ж");
+
+    assert_eq!(s.overwrite_at(6, "Ж"), Ok(()));
+    assert_eq!(s.overwrite_at(7, ""), Ok(()));
+    assert_eq!(s.as_str(), "id=ёa;Ж");
+}
+
+#[test]
+fn strip_chars() {
+    let mut s = microstr!("\x1b[31mОшибка\x1b[0m: disk\0 full\x7f\n");
+    s.strip_control_chars();
+    assert_eq!(s.as_str(), "[31mОшибка[0m: disk full\n");
+
+    s.strip_chars(|c| c.is_ascii_digit() || "[m\n".contains(c));
+    assert_eq!(s.as_str(), "Ошибка: disk full");
+    s.strip_chars(|c| !c.is_ascii());
+    assert_eq!(s.as_str(), ": disk full");
+    s.strip_chars(|_| true);
+    assert!(s.is_empty());
+}
+
+#[test]
+fn map_retain() {
+    // Dropping and narrowing
+    let mut s = microstr!("ж1ё2й3");
+    s.map_retain(|c| if c.is_ascii_digit() { None } else { Some('_') });
+    assert_eq!(s.as_str(), "___");
+
+    // Widening past the read position
+    let mut s = microstr!("abcd", 12);
+    s.map_retain(|c| if c == 'b' { None } else { Some('ё') });
+    assert_eq!(s.as_str(), "ёёё");
+
+    // Widening past the capacity truncates
+    let mut s = microstr!("abcdef", 7);
+    let mut calls = 0;
+    s.map_retain(|_| {
+        calls += 1;
+        Some('🦀')
+    });
+    assert_eq!(s.as_str(), "🦀");
+    assert_eq!(calls, 2);
+
+    // Widening with no room for the unread tail, later chars are dropped
+    let mut s = microstr!("aXYZ");
+    s.map_retain(|c| match c {
+        'a' => Some('ж'),
+        'X' | 'Y' => None,
+        c => Some(c),
+    });
+    assert_eq!(s.as_str(), "жZ");
+
+    // Random maps agree with `filter_map` truncated to the capacity
+    let mut next = random_below(0x9E37_79B9);
+    let alphabet = MIXED_WIDTH;
+    for _ in 0..500 {
+        let mut s = MicroStr::<16>::new();
+        for _ in 0..next(17) {
+            let _ = s.push(alphabet[next(alphabet.len())]);
+        }
+        let map: Vec<Option<char>> = (0..alphabet.len())
+            .map(|_| [None, Some(alphabet[next(alphabet.len())])][next(2)])
+            .collect();
+        let f = |c: char| map[alphabet.iter().position(|&a| a == c).unwrap()];
+        let expected: String = s.chars().filter_map(f).collect();
+        s.map_retain(f);
+        assert_eq!(s.as_str(), MicroStr::<16>::from_const(&expected).as_str());
+    }
+}
+
+#[test]
+fn rotate() {
+    let mut s = microstr!("a→б🦀");
+    s.rotate_left(1);
+    assert_eq!(s.as_str(), "→б🦀a");
+    s.rotate_left(6); // 6 % 4 == 2
+    assert_eq!(s.as_str(), "🦀a→б");
+    assert!(s.as_str_checked().is_ok());
+    s.rotate_right(3);
+    assert_eq!(s.as_str(), "a→б🦀");
+    s.rotate_right(4);
+    assert_eq!(s.as_str(), "a→б🦀");
+
+    let mut empty = MicroStr::<4>::new();
+    empty.rotate_left(3);
+    empty.rotate_right(3);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn marquee_step() {
+    const S: MicroStr<16> = microstr!("Бег🦀 ", 16);
+    let mut s = S.clone();
+    s.marquee_step();
+    assert_eq!(s.as_str(), "ег🦀 Б");
+    s.marquee_step();
+    s.marquee_step();
+    assert_eq!(s.as_str(), "🦀 Бег");
+
+    let mut s = S.clone();
+    for step in 1..=S.char_count() {
+        s.marquee_step();
+        assert!(s.as_str_checked().is_ok());
+        assert_eq!(s == S, step == S.char_count());
+    }
+
+    // Full buffer
+    let mut s = microstr!("🦀ж");
+    s.marquee_step();
+    assert_eq!(s.as_str(), "ж🦀");
+
+    let mut empty = MicroStr::<4>::new();
+    empty.marquee_step();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn replace_all() {
+    let mut s = microstr!("a--b--c");
+    assert_eq!(s.replace_all("--", "-"), Ok(2));
+    assert_eq!(s.as_str(), "a-b-c");
+    assert_eq!(s.replace_all("-", ""), Ok(2));
+    assert_eq!(s.as_str(), "abc");
+    assert_eq!(s.replace_all("x", "y"), Ok(0));
+    assert_eq!(s.replace_all("", "y"), Ok(0));
+
+    let mut s = microstr!("aaaa", 8);
+    assert_eq!(s.replace_all("aa", "b"), Ok(2));
+    assert_eq!(s.as_str(), "bb");
+
+    // Shorter to longer, fits
+    let mut s = microstr!("x.y.z", 16);
+    assert_eq!(s.replace_all(".", "::"), Ok(2));
+    assert_eq!(s.as_str(), "x::y::z");
+
+    // Shorter to longer, exactly at capacity
+    let mut s = microstr!("x.y.z", 7);
+    assert_eq!(s.replace_all(".", "::"), Ok(2));
+    assert_eq!(s.as_str(), "x::y::z");
+
+    // Last replacement cut by capacity
+    let mut s = microstr!("x.y.z", 5);
+    assert_eq!(s.replace_all(".", "::"), Err(1));
+    assert_eq!(s.as_str(), "x::y:");
+
+    // Only tail is cut by capacity
+    let mut s = microstr!("x.y.z", 6);
+    assert_eq!(s.replace_all(".", "::"), Err(2));
+    assert_eq!(s.as_str(), "x::y::");
+
+    // Tail after replacements is truncated
+    let mut s = microstr!("1.2.345", 11);
+    assert_eq!(s.replace_all(".", "::"), Ok(2));
+    assert_eq!(s.replace_all("::", "→"), Ok(2));
+    assert_eq!(s.as_str(), "1→2→345");
+    assert_eq!(s.replace_all("→", "=>>>"), Err(2));
+    assert_eq!(s.as_str(), "1=>>>2=>>>3");
+
+    // Multi-byte chars are not split
+    let mut s = microstr!("a b ж", 8);
+    assert_eq!(s.replace_all(" ", "__"), Ok(2));
+    assert_eq!(s.as_str(), "a__b__ж");
+    let mut s = microstr!("a b ж", 7);
+    assert_eq!(s.replace_all(" ", "__"), Err(2));
+    assert_eq!(s.as_str(), "a__b__");
+    let mut s = microstr!("ab", 5);
+    assert_eq!(s.replace_all("b", "ббб"), Err(0));
+    assert_eq!(s.as_str(), "aбб");
+
+    // Self-overlapping pattern is matched left to right
+    let mut s = microstr!("aaaaa", 16);
+    assert_eq!(s.replace_all("aa", "bbb"), Ok(2));
+    assert_eq!(s.as_str(), "bbbbbba");
+
+    // Many occurrences agree with `str::replace`
+    let text = "ж.ж.ж.ж.ж.ж.ж.ж.ж.ж.ж.ж.ж.ж.ж.ж.";
+    let mut s = MicroStr::<128>::from_const(text);
+    assert_eq!(s.replace_all(".", "→"), Ok(16));
+    assert_eq!(s.as_str(), text.replace('.', "→"));
+    assert!(s.as_str_checked().is_ok());
+}
+
+#[test]
+fn indent() {
+    let mut s = microstr!("one\ntwo\nthree", 32);
+    assert_eq!(s.indent("> "), Ok(()));
+    assert_eq!(s.as_str(), "> one\n> two\n> three");
+
+    // Exactly at capacity
+    let mut s = microstr!("one\ntwo\nthree", 19);
+    assert_eq!(s.indent("> "), Ok(()));
+    assert_eq!(s.as_str(), "> one\n> two\n> three");
+
+    // Capacity is hit on the last line
+    let mut s = microstr!("one\ntwo\nthree", 16);
+    assert_eq!(s.indent("> "), Err(2));
+    assert_eq!(s.as_str(), "> one\n> two\n> th");
+
+    // Final newline doesn't start a line, empty lines are indented
+    let mut s = microstr!("a\n\nb\n", 16);
+    assert_eq!(s.indent("- "), Ok(()));
+    assert_eq!(s.as_str(), "- a\n- \n- b\n");
+
+    let mut s = MicroStr::<8>::new();
+    assert_eq!(s.indent("  "), Ok(()));
+    assert!(s.is_empty());
+    let mut s = microstr!("x\ny");
+    assert_eq!(s.indent(""), Ok(()));
+    assert_eq!(s.as_str(), "x\ny");
+
+    // Multi-byte prefix is not split
+    let mut s = microstr!("a\nb", 6);
+    assert_eq!(s.indent("→"), Err(1));
+    assert_eq!(s.as_str(), "→a\n");
+
+    // Agrees with a line-by-line reference, truncated to any capacity
+    fn check<const CAP: usize>(text: &str, expected: &str) {
+        let mut s = MicroStr::<CAP>::from_const(text);
+        let result = s.indent("│ ");
+        assert_eq!(s.as_str(), MicroStr::<CAP>::from_const(expected).as_str(), "CAP = {CAP}");
+        if text.len() <= CAP {
+            assert_eq!(result.is_ok(), expected.len() <= CAP, "CAP = {CAP}");
+        }
+    }
+    let text = "ёж\n\n🦀 краб\r\nend\n";
+    let expected: String = text.split_inclusive('\n').map(|line| format!("│ {line}")).collect();
+    check::<0>(text, &expected);
+    check::<3>(text, &expected);
+    check::<7>(text, &expected);
+    check::<11>(text, &expected);
+    check::<13>(text, &expected);
+    check::<20>(text, &expected);
+    check::<29>(text, &expected);
+    check::<36>(text, &expected);
+    check::<40>(text, &expected);
+    check::<41>(text, &expected);
+    check::<64>(text, &expected);
+}
+
+#[test]
+fn default() {
+    let s: MicroStr<10> = MicroStr::default();
+    assert_eq!(s.as_str(), "");
+    assert_eq!(s.len(), 0);
+}
+
+#[test]
+fn compare() {
+    let s1 = microstr!("hello", 5);
+    let s2 = microstr!("hello", 10);
+    let s3 = microstr!("world", 5);
+
+    assert_eq!(s1, s2);
+    assert_ne!(s1, s3);
+    assert_ne!(s2, s3);
+}
+
+#[test]
+fn trimmed() {
+    let yes = microstr!("yes  ", 10);
+    assert!(Trimmed(&yes) == "yes");
+    assert!(yes.trimmed() == *"yes");
+    assert!(yes.trimmed() != "\tyes\n"); // The other side is not trimmed
+    assert!(yes.trimmed() == microstr!(" yes").trimmed());
+    assert!(yes.trimmed() != "no");
+
+    let a = microstr!("  apple", 10);
+    let b = microstr!("banana  ", 10);
+    assert!(a.trimmed() < b.trimmed());
+    assert_eq!(yes.trimmed().cmp(&microstr!("yes", 10).trimmed()), core::cmp::Ordering::Equal);
+}
+
+#[test]
+fn eq_bytes() {
+    let a = microstr!("ключ", 16);
+    let b = microstr!("ключ", 16);
+    let c = microstr!("ключи", 16);
+    let d = microstr!("ключь", 16);
+    assert!(a.eq_bytes(&b));
+    assert!(!a.eq_bytes(&c));
+    assert!(!c.eq_bytes(&d));
+    assert!(MicroStr::<4>::new().eq_bytes(&MicroStr::new()));
+
+    // Agrees with `==` on every pair
+    let keys = ["", "a", "ab", "abc", "ключ", "ключи", "🦀", "🦀🦀"];
+    let mut matches = 0;
+    for x in keys {
+        for y in keys {
+            let (x, y) = (MicroStr::<16>::from_const(x), MicroStr::<16>::from_const(y));
+            assert_eq!(x.eq_bytes(&y), x == y);
+            matches += x.eq_bytes(&y) as usize;
+        }
+    }
+    assert_eq!(matches, keys.len());
+}
+
+#[test]
+fn cmp_ignore_ascii_case() {
+    use core::cmp::Ordering;
+
+    let mut fruits = [microstr!("Banana", 8), microstr!("apple", 8), microstr!("Cherry", 8), microstr!("APRICOT", 8)];
+    fruits.sort_by(|a, b| a.cmp_ignore_ascii_case(b));
+    assert_eq!(fruits, [microstr!("apple", 8), microstr!("APRICOT", 8), microstr!("Banana", 8), microstr!("Cherry", 8)]);
+
+    let s = microstr!("Hello");
+    assert_eq!(s.cmp_ignore_ascii_case("hELLO"), Ordering::Equal);
+    assert_eq!(s.cmp_ignore_ascii_case("hello!"), Ordering::Less);
+    assert_eq!(s.cmp_ignore_ascii_case("HELL"), Ordering::Greater);
+    assert_eq!(s.cmp_ignore_ascii_case("[ello"), Ordering::Greater); // 'H' < '[' < 'h'
+    assert_eq!(microstr!("Ёж").cmp_ignore_ascii_case("ёж"), Ordering::Less);
+}
+
+#[test]
+fn cmp_by_len() {
+    use core::cmp::Ordering;
+
+    let mut words = ["ёж", "b", "abc", "a", "zz"].map(MicroStr::<8>::from_const);
+    words.sort_by(MicroStr::cmp_by_len);
+    assert_eq!(words.each_ref().map(|w| w.as_str()), ["a", "b", "zz", "abc", "ёж"]);
+
+    let s = microstr!("ab", 4);
+    assert_eq!(s.cmp_by_len(&microstr!("ab", 4)), Ordering::Equal);
+    assert_eq!(s.cmp_by_len(&microstr!("b", 4)), Ordering::Greater);
+    assert_eq!(s.cmp_by_len(&microstr!("aa", 4)), Ordering::Greater);
+    assert_eq!(s.cmp_by_len(&microstr!("ж", 4)), Ordering::Less); // Same length in bytes
+}
+
+#[test]
+fn deref() {
+    let s = microstr!("Hello", 15);
+
+    assert!( s.is_ascii() );
+    assert_eq!(s.to_ascii_uppercase(), "HELLO");
+}
+
+#[test]
+fn parse() {
+    assert_eq!(microstr!("-17").parse::<i32>(), Ok(-17));
+    assert_eq!(microstr!("2.5").parse::<f32>(), Ok(2.5));
+    assert!(microstr!("300").parse::<u8>().is_err());
+    assert!(microstr!("").parse::<u8>().is_err());
+
+    const N: Option<u64> = microstr!("18446744073709551615").parse_u64();
+    assert_eq!(N, Some(u64::MAX));
+    assert_eq!(microstr!("0042").parse_u64(), Some(42));
+    assert_eq!(microstr!("18446744073709551616").parse_u64(), None);
+    assert_eq!(microstr!("").parse_u64(), None);
+    assert_eq!(microstr!("+1").parse_u64(), None);
+    assert_eq!(microstr!("1 2").parse_u64(), None);
+    assert_eq!(microstr!("١٢").parse_u64(), None); // non-ASCII digits
+}
+
+#[test]
+fn parser_input() {
+    /// Parses `key=value;` pairs, returns the first pair and the rest of input
+    fn parse(input: &str) -> Option<((&str, &str), &str)> {
+        let (key, rest) = input.split_once('=')?;
+        let (value, rest) = rest.split_once(';')?;
+        Some(((key.trim(), value.trim()), rest))
+    }
+
+    let s = microstr!("width = 10; height=20;");
+    let (first, rest) = parse(s.as_input()).unwrap();
+    assert_eq!(first, ("width", "10"));
+    let (second, rest) = parse(rest).unwrap();
+    assert_eq!(second, ("height", "20"));
+    assert_eq!(rest, "");
+
+    assert_eq!(parse(&s), parse(s.as_ref()));
+    assert_eq!(parse(&microstr!("broken")), None);
+}
+
+#[test]
+fn fmt() {
+    let mut s = microstr!("", 50);
+    assert_eq!(s.write_char('a'), Ok(()));
+    assert_eq!(s.write_str("bcdef"), Ok(()));
+    assert_eq!(s.write_fmt(format_args!("; {} = {}", "var", 10)), Ok(()));
+
+    assert_eq!(s.as_str(), "abcdef; var = 10");
+
+    let (name, value) = ("x", 4.5);
+    assert_eq!(s.write_fmt(format_args!(", {name} = {value}")), Ok(()));
+    assert_eq!(s.as_str(), "abcdef; var = 10, x = 4.5");
+
+    let mut s = microstr!("", 4);
+    assert_eq!(s.write_fmt(format_args!("{}{}", value, value)), Err(core::fmt::Error));
+    assert_eq!(s.as_str(), "4.54");
+}
+
+#[test]
+fn concat_all() {
+    const PARTS: [MicroStr<8>; 3] = [microstr!("ёж", 8), microstr!(" и ", 8), microstr!("ёжик", 8)];
+    const ALL: MicroStr<32> = MicroStr::concat_all(&PARTS);
+    assert_eq!(ALL.as_str(), "ёж и ёжик");
+
+    // Truncated on the last part
+    assert_eq!(MicroStr::<12>::concat_all(&PARTS).as_str(), "ёж и ёж");
+    assert_eq!(MicroStr::<13>::concat_all(&PARTS).as_str(), "ёж и ёж"); // 'и' is not split
+    assert!(MicroStr::<4>::concat_all::<8>(&[]).is_empty());
+}
+
+#[test]
+fn from_chars() {
+    const ABC: MicroStr<8> = MicroStr::from_chars(['a', 'b', 'c']);
+    const { assert!(ABC.bytes_len() == 3) };
+    assert_eq!(ABC.as_str(), "abc");
+
+    const DIGITS: MicroStr<10> = {
+        let mut chars = ['0'; 10];
+        let mut i = 0;
+        while i < 10 {
+            chars[i] = char::from_digit(9 - i as u32, 10).unwrap();
+            i += 1;
+        }
+        MicroStr::from_chars(chars)
+    };
+    assert_eq!(DIGITS.as_str(), "9876543210");
+
+    assert_eq!(MicroStr::<5>::from_chars(['a', 'ж', '🦀']).as_str(), "aж");
+    assert!(MicroStr::<5>::from_chars([]).is_empty());
+}
+
+#[test]
+fn from_str_lossy() {
+    const S: MicroStr<8> = MicroStr::from_str_lossy("ok");
+    assert_eq!(S.as_str(), "ok");
+
+    let s = MicroStr::<7>::from_str_lossy("Привет");
+    assert_eq!(s.as_str(), "При"); // Truncated, 'в' doesn't fit in the last byte
+    let (truncated, _) = MicroStr::<7>::from_str("Привет").unwrap_err();
+    assert_eq!(s, truncated);
+
+    assert!(MicroStr::<0>::from_str_lossy("abc").is_empty());
+}
+
+#[test]
+fn from_fmt() {
+    let count = 3;
+    let s = MicroStr::<32>::from_fmt(format_args!("{count} × {:?} = {}", "ab", count * 2));
+    assert_eq!(s.as_str(), "3 × \"ab\" = 6");
+
+    let s = MicroStr::<5>::from_fmt(format_args!("{}: {}", "ключ", count));
+    assert_eq!(s.as_str(), "кл"); // Truncated
+}
+
+#[test]
+fn truncator() {
+    let s = "Hello, world";
+    assert_eq!(utf8_truncator(s, 0), 0);    // ""
+    assert_eq!(utf8_truncator(s, 20), 12);  // "Hello, world"
+    assert_eq!(utf8_truncator(s, 10), 10);  // "Hello, wor"
+
+    let s = "Привет, мир";
+    assert_eq!(utf8_truncator(s, 10), 10);  // "Приве"
+    assert_eq!(utf8_truncator(s, 11), 10);  // "Приве"
+    assert_eq!(utf8_truncator(s, 12), 12);  // "Привет"
+    assert_eq!(utf8_truncator(s, 13), 13);  // "Привет,"
+
+    let s = "你好，世界";
+    assert_eq!(utf8_truncator(s, 3), 3);  // "你"
+    assert_eq!(utf8_truncator(s, 4), 3);  // "你"
+    assert_eq!(utf8_truncator(s, 5), 3);  // "你"
+    assert_eq!(utf8_truncator(s, 6), 6);  // "你好"
+
+    let s = "🔥🦀❗️";
+    assert_eq!(utf8_truncator(s, 3), 0);  // ""
+    assert_eq!(utf8_truncator(s, 4), 4);  // "🔥"
+    assert_eq!(utf8_truncator(s, 5), 4);  // "🔥"
+    assert_eq!(utf8_truncator(s, 6), 4);  // "🔥"
+    assert_eq!(utf8_truncator(s, 7), 4);  // "🔥"
+    assert_eq!(utf8_truncator(s, 8), 8);  // "🔥🦀"
+}
+
+#[test]
+fn eq_ord_hash_coherence() {
+    use std::collections::{BTreeSet, HashSet};
+    use std::hash::{BuildHasher, RandomState};
+
+    let hasher = RandomState::new();
+    let words = ["", "a", "ab", "b", "ж", "ёж", "z", "Z", "🦀"];
+    for x in words {
+        for y in words {
+            let (a, b) = (MicroStr::<8>::from_const(x), MicroStr::<16>::from_const(y));
+            // Cross-capacity comparisons agree with `str`
+            assert_eq!(a == b, x == y);
+            assert_eq!(a.partial_cmp(&b), Some(x.cmp(y)));
+            // Equal content hashes equally, and like the `str`
+            if a == b {
+                assert_eq!(hasher.hash_one(&a), hasher.hash_one(&b));
+                assert_eq!(hasher.hash_one(&a), hasher.hash_one(x));
+            }
+            // Same capacity `Ord` agrees with `PartialOrd`
+            let c = MicroStr::<8>::from_const(y);
+            assert_eq!(Some(a.cmp(&c)), a.partial_cmp(&c));
+        }
+    }
+
+    let set: BTreeSet<MicroStr<8>> = words.iter().rev().map(|w| MicroStr::from_const(w)).collect();
+    assert!(set.iter().map(|s| s.as_str()).eq(BTreeSet::from(words).into_iter()));
+
+    let set: HashSet<MicroStr<8>> = ["ёж", "ёж", "ж"].iter().map(|w| MicroStr::from_const(w)).collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&microstr!("ёж", 8)));
+}
+
+/// Every public method on `MicroStr<0>`: nothing may be written to the empty buffer
+mod cap_zero {
+    use core::{cmp::Ordering, fmt::Write};
+    use super::super::{MicroFmt, MicroStr, MicroStrWriter, microstr, to_hex, const_concat, fits_in};
+
+    type Zero = MicroStr<0>;
+
+    #[test]
+    fn building() {
+        const FROM_CONST: Zero = MicroStr::from_const("abc");
+        const LOSSY: Zero = MicroStr::from_str_lossy("ж");
+        const CHARS: Zero = MicroStr::from_chars(['a']);
+        const PARTS: Zero = MicroStr::concat_all(&[microstr!("ab")]);
+        const { assert!(FROM_CONST.is_empty() && LOSSY.is_empty() && CHARS.is_empty() && PARTS.is_empty()) };
+
+        assert!(Zero::new().is_empty());
+        assert!(Zero::default().is_empty());
+        assert_eq!(Zero::from_str("").map(|s| s.bytes_len()), Ok(0));
+        assert!(matches!(Zero::from_str("a"), Err((s, 0)) if s.is_empty()));
+        assert!(unsafe { Zero::from_str_unchecked("") }.is_empty());
+        assert!(unsafe { Zero::from_raw_buffer([]) }.is_empty());
+        assert!(unsafe { Zero::from_raw_buffer([b'a', b'b']) }.is_empty());
+        assert!(Zero::from_fmt(format_args!("{}", 42)).is_empty());
+        assert!(Zero::try_from_ascii("abc").unwrap().is_empty());
+        assert!(Zero::from_ascii(b"abc").unwrap().is_empty());
+        assert!(Zero::from_utf16(&[0x61]).unwrap().is_empty());
+        assert!(Zero::try_from("a".as_bytes()).is_err());
+        assert!(Zero::try_from(&[][..]).unwrap().is_empty());
+        assert!(42.to_microstr::<0>().is_empty());
+        assert!(to_hex::<0>(b"ab").is_empty());
+        assert!(const_concat::<2, 2, 0>(&microstr!("ab"), &microstr!("cd")).is_empty());
+        assert!(fits_in::<0>(""));
+        assert!(!fits_in::<0>("a"));
+    }
+
+    #[test]
+    fn getters() {
+        let mut s = Zero::new();
+        assert_eq!(s.as_ptr(), s.as_ptr_range().end);
+        let _ = s.as_mut_ptr();
+        assert_eq!(s.capacity(), 0);
+        assert_eq!(s.byte_capacity(), 0);
+        assert_eq!(s.max_ascii_chars(), 0);
+        assert_eq!(s.extra_capacity(), 0);
+        assert!(!s.can_push('a'));
+        assert!(s.reserve_exact_check(0));
+        assert!(!s.reserve_exact_check(1));
+        assert!(s.is_empty() && s.is_blank() && s.is_ascii());
+        assert_eq!((s.bytes_len(), s.len(), s.char_count()), (0, 0, 0));
+        assert_eq!(s.bytes_needed_for_chars(3), 0);
+        assert_eq!((s.byte_at(0), s.first_byte(), s.last_byte(), s.char_at(0)), (None, None, None, None));
+    }
+
+    #[test]
+    fn pushers() {
+        let mut s = Zero::new();
+        assert_eq!(s.push('a'), Err(()));
+        assert_eq!(s.append_char_n('a', 3), 0);
+        assert_eq!(s.push_str("a"), Err(0));
+        assert_eq!(s.push_str(""), Ok(()));
+        assert_eq!(s.write_str_counted("ab"), 0);
+        assert_eq!(s.try_write_fmt(format_args!("{}", 1)), Err(0));
+        assert_eq!(s.push_line(""), Err(0));
+        assert_eq!(s.push_interleaved("a", "b", ','), Err(0));
+        assert_eq!(s.push_bytes_iter(*b"ab"), Err(0));
+        assert_eq!(s.prepend("ab"), Err(0)); // The whole prefix is dropped
+        assert_eq!(s.prepend(""), Ok(()));
+        assert_eq!(s.try_insert_str(0, "a"), Err(()));
+        assert_eq!(s.try_insert_str(0, ""), Ok(()));
+        assert!(s.write_char('a').is_err());
+        assert!(s.write_str("a").is_err());
+        assert!(write!(s, "{}", 1).is_err());
+        s.extend([microstr!("a")]);
+
+        let mut w = MicroStrWriter::new(&mut s);
+        assert!(w.write_str("ab").is_ok());
+        assert_eq!(w.dropped(), 1);
+
+        let mut tx = s.transaction();
+        assert_eq!(tx.push('a'), Err(()));
+        assert_eq!(tx.push_str("a"), Err(0));
+        tx.commit();
+
+        assert!(s.is_empty());
+        unsafe { s.push_str_unchecked("") };
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn converters() {
+        let mut s = Zero::new();
+        assert_eq!(s.as_str(), "");
+        assert_eq!(s.as_str_checked(), Ok(""));
+        assert_eq!(s.as_input(), "");
+        assert!(s.parse::<u8>().is_err());
+        assert_eq!(s.parse_u64(), None);
+        assert_eq!(s.as_str_mut(), "");
+        assert_eq!(s.as_bytes(), b"");
+        assert_eq!(s.as_mut_bytes(), b"");
+        assert_eq!(unsafe { s.as_mut_array() }, &[0u8; 0]);
+        assert_eq!(s.encode_utf16_into(&mut [0; 4]), 0);
+        assert_eq!(s.copy_to_slice(&mut [0; 4]), 0);
+        assert_eq!(s.from_hex::<0>(), Ok([]));
+        let mut dst = microstr!("old");
+        s.clone_into_cap(&mut dst);
+        assert!(dst.is_empty());
+        microstr!("new").clone_into_cap(&mut s);
+        assert!(s.is_empty());
+        assert_eq!(format!("{}", s.display_truncated(0)), "");
+        assert_eq!(s.clone().into_raw_buffer(), []);
+        assert!(s.bytes_mut_guard().is_empty());
+        assert_eq!(s.to_vec(), b"");
+        assert_eq!(s.to_cstring().unwrap().as_bytes(), b"");
+        assert_eq!(String::from(s), "");
+    }
+
+    #[test]
+    fn comparison_and_searching() {
+        let s = Zero::new();
+        assert!(s.eq_bytes(&Zero::new()));
+        assert_eq!(s, MicroStr::<4>::new());
+        assert_eq!(s.cmp(&Zero::new()), Ordering::Equal);
+        assert_eq!(s.cmp_ignore_ascii_case(""), Ordering::Equal);
+        assert_eq!(s.cmp_by_len(&Zero::new()), Ordering::Equal);
+        assert!(s.contains_str("") && !s.contains_str("a"));
+        assert!(s.starts_with_bytes(b"") && s.ends_with_bytes(b""));
+        assert_eq!(s.count_chars_if(|_| true), 0);
+        assert_eq!((s.count_char_matches('a'), s.count_str_matches("a")), (0, 0));
+        assert_eq!(s.match_positions::<2>('a'), ([0, 0], 0));
+        assert_eq!(s.position_of_nth(0, |_| true), None);
+        assert!(s.all_chars(|_| false) && !s.any_char(|_| true) && s.is_alphanumeric_ascii());
+        assert_eq!(s.partition_point(|_| true), 0);
+        assert!(s.char_boundaries().eq([0]));
+        assert_eq!(s.split_at_byte(0), Some(("", "")));
+        assert_eq!(s.split_once_char(','), None);
+        assert_eq!(s.rsplit_once_char(','), None);
+        assert_eq!(s.split_last_char(), None);
+        assert_eq!(s.split_terminator_into::<2>(',').1, 0);
+        assert!(s.map_chars(|c| c).is_empty());
+        assert!(s.filter_chars(|_| true).is_empty());
+        assert!(s.trimmed() == "");
+    }
+
+    #[test]
+    fn modificators() {
+        let mut s = Zero::new();
+        s.clear();
+        assert!(s.take().is_empty());
+        unsafe {
+            s.set_len(0);
+            s.move_bytes(0, 0, 0);
+            s.fix_char_boundary();
+        }
+        s.truncate(0);
+        s.truncate(5);
+        assert_eq!(s.overwrite_at(0, ""), Ok(()));
+        assert_eq!(s.overwrite_at(0, "a"), Err(()));
+        s.truncate_bytes_floor(0);
+        s.truncate_bytes_exact(0);
+        s.truncate_ellipsis(0);
+        s.truncate_ellipsis(5);
+        assert_eq!(s.replace_tail(0, "a"), Err(0));
+        s.trim_matches_char(' ');
+        s.map_ascii_bytes(|b| b);
+        s.strip_chars(|_| true);
+        s.strip_control_chars();
+        s.pad_center(5, '*');
+        s.map_retain(Some);
+        s.rotate_left(1);
+        s.rotate_right(1);
+        s.marquee_step();
+        assert_eq!(s.replace_all("a", "b"), Ok(0));
+        assert!(s.is_empty());
+    }
+}
+
+/* LIST */
+
+#[test]
+fn list() {
+    let mut list: MicroStrList<8, 3> = MicroStrList::new();
+    assert!(list.is_empty());
+    assert_eq!(list.get(0), None);
+
+    for word in ["set", "volume", "11"] {
+        assert!(list.push(MicroStr::from_const(word)).is_ok());
+    }
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.capacity(), 3);
+    let rejected = list.push(microstr!("extra", 8)).unwrap_err();
+    assert_eq!(rejected.as_str(), "extra");
+    assert_eq!(list.len(), 3);
+
+    assert_eq!(list.get(1).unwrap().as_str(), "volume");
+    assert_eq!(list.get(3), None);
+    assert!(list.iter().map(|s| s.as_str()).eq(["set", "volume", "11"]));
+    assert_eq!((&list).into_iter().count(), 3);
+}
+
+#[test]
+fn valid_prefix() {
+    let s = b"Hello, world";
+    assert_eq!(valid_utf8_prefix_len(s, 0), 0);    // ""
+    assert_eq!(valid_utf8_prefix_len(s, 20), 12);  // "Hello, world"
+    assert_eq!(valid_utf8_prefix_len(s, 10), 10);  // "Hello, wor"
+
+    let s = "Привет, мир".as_bytes();
+    assert_eq!(valid_utf8_prefix_len(s, 10), 10);  // "Приве"
+    assert_eq!(valid_utf8_prefix_len(s, 11), 10);  // "Приве"
+    assert_eq!(valid_utf8_prefix_len(s, 12), 12);  // "Привет"
+
+    let s = "🔥🦀".as_bytes();
+    assert_eq!(valid_utf8_prefix_len(s, 3), 0);  // ""
+    assert_eq!(valid_utf8_prefix_len(s, 7), 4);  // "🔥"
+    assert_eq!(valid_utf8_prefix_len(s, 8), 8);  // "🔥🦀"
+
+    let s = b"ok\xd0\x9f\xff\xd0\x9f";
+    assert_eq!(valid_utf8_prefix_len(s, 3), 2);  // "ok"
+    assert_eq!(valid_utf8_prefix_len(s, 4), 4);  // "okП"
+    assert_eq!(valid_utf8_prefix_len(s, 10), 4); // stops at 0xff
+    assert_eq!(valid_utf8_prefix_len(b"\x9fabc", 4), 0); // starts with continuation byte
+    assert_eq!(valid_utf8_prefix_len(b"a\xed\xa0\x80", 4), 1); // surrogate is invalid
+
+    const LEN: usize = valid_utf8_prefix_len("Кот".as_bytes(), 5);
+    assert_eq!(LEN, 4);
+}
+
+/* STD ONLY */
+
+#[test]
+fn output() {
+    let s = microstr!("Some Output", 25);
+    assert_eq!(format!("{:?}", s), "MicroStr<25>{\"Some Output\"}");
+    assert_eq!(format!("{}", s), "Some Output");
+}
+
+#[test]
+fn debug_escaped() {
+    let s = microstr!("a \"quoted\" word");
+    assert_eq!(format!("{s:?}"), r#"MicroStr<15>{"a \"quoted\" word"}"#);
+
+    let s = microstr!("line 1\nline 2\t\0\u{7}", 20);
+    assert_eq!(format!("{s:?}"), r#"MicroStr<20>{"line 1\nline 2\t\0\u{7}"}"#);
+
+    let s = microstr!("ёж\\");
+    assert_eq!(format!("{s:?}"), r#"MicroStr<5>{"ёж\\"}"#); // Non-ASCII stays as is
+    assert_eq!(format!("{s:?}"), format!("MicroStr<5>{{{:?}}}", s.as_str()));
+}
+
+#[test]
+fn display_flags() {
+    let s = microstr!("Привет", 16);
+    assert_eq!(format!("{s:>8}"), "  Привет");
+    assert_eq!(format!("{s:<8}|"), "Привет  |");
+    assert_eq!(format!("{s:*^10}"), "**Привет**");
+    assert_eq!(format!("{s:.3}"), "При"); // Precision counts chars, not bytes
+    assert_eq!(format!("{s:>5.2}"), "   Пр");
+    assert_eq!(format!("{s:3}"), "Привет"); // Width never truncates
+    assert_eq!(format!("{s:.10}"), "Привет");
+    assert_eq!(format!("{s:>8}"), format!("{:>8}", s.as_str()));
+}
+
+#[test]
+fn transaction() {
+    use core::fmt::Write;
+
+    let mut s = microstr!("id:", 10);
+
+    // Overflowing sequence is rolled back
+    {
+        let mut tx = s.transaction();
+        assert_eq!(tx.push_str("42"), Ok(()));
+        assert_eq!(tx.push(','), Ok(()));
+        assert_eq!(tx.as_str(), "id:42,");
+        assert_eq!(tx.push_str("ёжик"), Err(4));
+    }
+    assert_eq!(s.as_str(), "id:");
+
+    // Formatting through the guard
+    let mut tx = s.transaction();
+    let id = 7;
+    write!(tx, "{id},").unwrap();
+    tx.commit();
+    assert_eq!(s.as_str(), "id:7,");
+
+    let mut tx = s.transaction();
+    assert!(write!(tx, "{}", 1234567).is_err());
+    drop(tx);
+    assert_eq!(s.as_str(), "id:7,");
+}
+
+#[test]
+fn write_char() {
+    use core::fmt::Write;
+
+    let mut s = MicroStr::<3>::new();
+    assert_eq!(s.write_char('ж'), Ok(()));
+    assert_eq!(s.write_char('ж'), Err(core::fmt::Error));
+    assert_eq!(s.write_char('a'), Ok(()));
+    assert_eq!(s.write_char('a'), Err(core::fmt::Error));
+    assert_eq!(s.as_str(), "жa");
+
+    let mut zero = MicroStr::<0>::new();
+    assert_eq!(zero.write_char('a'), Err(core::fmt::Error));
+    assert_eq!(zero.write_str(""), Ok(()));
+    assert!(zero.is_empty());
+}
+
+#[test]
+fn micro_str_writer() {
+    use core::fmt::Write;
+
+    let mut s = MicroStr::<10>::new();
+    let mut w = MicroStrWriter::new(&mut s);
+    write!(w, "{}", 42).unwrap();
+    assert_eq!(w.dropped(), 0);
+    let (level, msg) = ("WARN", "disk full");
+    write!(w, "[{level}] {msg}").unwrap();
+    assert_eq!(w.dropped(), 1); // "disk full" truncated
+    w.write_char('!').unwrap();
+    assert_eq!(w.dropped(), 2); // Dropped entirely
+    assert_eq!(s.as_str(), "42[WARN] d");
+}
+
+#[test]
+fn micro_fmt() {
+    struct Point(i32, i32);
+    impl core::fmt::Display for Point {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "({}, {})", self.0, self.1)
+        }
+    }
+
+    assert_eq!(12345.to_microstr::<8>().as_str(), "12345");
+    assert_eq!((-1.5f32).to_microstr::<8>().as_str(), "-1.5");
+    assert_eq!(u64::MAX.to_microstr::<4>().as_str(), "1844"); // Truncated
+    assert_eq!("ёж".to_microstr::<3>().as_str(), "ё");
+    assert_eq!(Point(3, -4).to_microstr::<16>().as_str(), "(3, -4)");
+    assert_eq!(Point(3, -4).to_microstr::<4>().as_str(), "(3, ");
+}
+
+#[test]
+fn display_truncated() {
+    let s = microstr!("Очень длинная строка");
+    assert_eq!(format!("{}", s.display_truncated(5)), "Очень…");
+    assert_eq!(format!("{}", s.display_truncated(0)), "…");
+    assert_eq!(format!("{}", s.display_truncated(20)), "Очень длинная строка");
+    assert_eq!(format!("{}", s.display_truncated(100)), "Очень длинная строка");
+}
+
+#[test]
+fn string() {
+    let string = String::from("Heap Allocated!");
+
+    let s = MicroStr::<20>::from(string);
+
+    assert_eq!(s.as_str(), "Heap Allocated!");
+
+    let return_string = String::from(s);
+
+    assert_eq!(return_string, "Heap Allocated!");
+}
+
+#[test]
+fn vec() {
+    let s = microstr!("Байты", 32);
+    let vec = s.to_vec();
+    assert_eq!(vec, "Байты".as_bytes());
+    assert_eq!(vec.len(), s.bytes_len());
+    assert_eq!(vec.capacity(), s.bytes_len());
+
+    let vec = s.into_vec();
+    assert_eq!(vec, "Байты".as_bytes());
+    assert_eq!(vec.capacity(), 10);
+}
+
+#[test]
+fn to_cstring() {
+    let c = microstr!("/dev/ttyЮ", 16).to_cstring().unwrap();
+    assert_eq!(c.to_str(), Ok("/dev/ttyЮ"));
+    assert_eq!(c.as_bytes_with_nul().len(), 11);
+
+    assert_eq!(MicroStr::<4>::new().to_cstring().unwrap().as_bytes_with_nul(), b"\0");
+
+    let err = microstr!("ab\0cd").to_cstring().unwrap_err();
+    assert_eq!(err.nul_position(), 2);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde() {
+    use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_ser_tokens, assert_tokens, Token};
+    use super::AsBytes;
+
+    let s = microstr!("{\"ключ\": 42}", 32);
+    assert_tokens(&s, &[Token::Str("{\"ключ\": 42}")]);
+    assert_ser_tokens(&AsBytes(&s), &[Token::Bytes("{\"ключ\": 42}".as_bytes())]);
+
+    // Byte-oriented formats
+    assert_de_tokens(&s, &[Token::Bytes("{\"ключ\": 42}".as_bytes())]);
+    assert_de_tokens(&s, &[Token::BorrowedBytes("{\"ключ\": 42}".as_bytes())]);
+    assert_de_tokens_error::<MicroStr<8>>(
+        &[Token::Bytes(b"ab\xFF")],
+        "invalid value: byte array, expected a string of at most 8 bytes",
+    );
+
+    // Too long for the capacity
+    assert_de_tokens_error::<MicroStr<4>>(
+        &[Token::Str("ёжик")],
+        "invalid length 8, expected a string of at most 4 bytes",
+    );
+    assert_de_tokens_error::<MicroStr<4>>(
+        &[Token::Bytes(b"abcde")],
+        "invalid length 5, expected a string of at most 4 bytes",
+    );
 }
\ No newline at end of file
@@ -0,0 +1,84 @@
+use core::{fmt, mem, ops::Deref};
+use super::MicroStr;
+
+/// A guard for all-or-nothing appends to a `MicroStr`.
+///
+/// Returned by [`MicroStr::transaction`]. Appends are done through the guard,
+/// and unless [`Transaction::commit`] is called, they are rolled back when it's dropped.
+///
+/// Only appending is allowed through the guard, so rolling back is just restoring the length.
+pub struct Transaction<'a, const CAP: usize> {
+    s: &'a mut MicroStr<CAP>,
+    start: usize,
+}
+
+impl<const CAP: usize> MicroStr<CAP> {
+    /// Starts a [`Transaction`]: a sequence of appends that can be aborted as a whole.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let mut s = microstr!("a=1", 8);
+    ///
+    /// let mut tx = s.transaction();
+    /// assert_eq!(tx.push_str(";b=2"), Ok(()));
+    /// tx.commit();
+    /// assert_eq!(s.as_str(), "a=1;b=2");
+    ///
+    /// let mut tx = s.transaction();
+    /// assert_eq!(tx.push_str(";c=3"), Err(1));
+    /// drop(tx); // Not committed, rolled back
+    /// assert_eq!(s.as_str(), "a=1;b=2");
+    /// ```
+    #[inline]
+    pub fn transaction(&mut self) -> Transaction<'_, CAP> {
+        let start = self.len;
+        Transaction { s: self, start }
+    }
+}
+
+impl<const CAP: usize> Transaction<'_, CAP> {
+    /// Appends a char, see [`MicroStr::push`].
+    #[inline]
+    #[allow(clippy::result_unit_err)]
+    pub fn push(&mut self, ch: char) -> Result<(), ()> {
+        self.s.push(ch)
+    }
+
+    /// Appends a string slice, see [`MicroStr::push_str`].
+    #[inline]
+    pub fn push_str(&mut self, s: &str) -> Result<(), usize> {
+        self.s.push_str(s)
+    }
+
+    /// Keeps everything appended through the guard.
+    #[inline]
+    pub fn commit(self) {
+        mem::forget(self);
+    }
+}
+
+impl<const CAP: usize> Deref for Transaction<'_, CAP> {
+    type Target = MicroStr<CAP>;
+
+    #[inline]
+    fn deref(&self) -> &MicroStr<CAP> {
+        self.s
+    }
+}
+
+impl<const CAP: usize> fmt::Write for Transaction<'_, CAP> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        fmt::Write::write_str(self.s, s)
+    }
+}
+
+impl<const CAP: usize> Drop for Transaction<'_, CAP> {
+    /// Rolls back all appends made through the guard.
+    fn drop(&mut self) {
+        // Content only grows through the guard, so `start` is still a char boundary
+        self.s.len = self.start;
+    }
+}
@@ -0,0 +1,77 @@
+use core::cmp::Ordering;
+use super::MicroStr;
+
+/// A borrowed view of a `MicroStr` that compares its content without leading and trailing whitespace.
+///
+/// Useful for matching config values where surrounding spaces are insignificant.
+/// A `str` on the other side is compared as is, two `Trimmed` views are both trimmed.
+///
+/// # Example
+///
+/// ```rust
+/// use microstr::*;
+/// let value = microstr!("  yes  ");
+/// assert!(Trimmed(&value) == "yes");
+/// assert!(value.trimmed() == microstr!("yes").trimmed());
+/// ```
+#[derive(Clone, Copy)]
+pub struct Trimmed<'a, const CAP: usize>(pub &'a MicroStr<CAP>);
+
+impl<const CAP: usize> Trimmed<'_, CAP> {
+    /// Returns the content without leading and trailing whitespace.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.0.trim()
+    }
+}
+
+impl<const CAP: usize> MicroStr<CAP> {
+    /// Returns a [`Trimmed`] view for whitespace-insensitive comparisons.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use microstr::*;
+    /// let s = microstr!("yes  ");
+    /// assert!(s.trimmed() == "yes");
+    /// ```
+    #[inline]
+    pub const fn trimmed(&self) -> Trimmed<'_, CAP> {
+        Trimmed(self)
+    }
+}
+
+impl<const CAP: usize> PartialEq<str> for Trimmed<'_, CAP> {
+    /// Compares trimmed content with `other` as is.
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const CAP: usize> PartialEq<&str> for Trimmed<'_, CAP> {
+    /// Compares trimmed content with `other` as is.
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const A: usize, const B: usize> PartialEq<Trimmed<'_, B>> for Trimmed<'_, A> {
+    fn eq(&self, other: &Trimmed<'_, B>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const CAP: usize> Eq for Trimmed<'_, CAP> {}
+
+impl<const CAP: usize> PartialOrd for Trimmed<'_, CAP> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const CAP: usize> Ord for Trimmed<'_, CAP> {
+    /// Orders lexicographically by trimmed content.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
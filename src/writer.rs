@@ -0,0 +1,49 @@
+use core::fmt;
+use super::MicroStr;
+
+/// A [`fmt::Write`] sink over a `MicroStr` that keeps going on overflow
+/// and counts the writes that didn't fit.
+///
+/// The bare `fmt::Write` impl of `MicroStr` stops formatting at the first overflow.
+/// `MicroStrWriter` truncates instead, so logging code gets as much of the message
+/// as fits, and can check [`MicroStrWriter::dropped`] afterwards.
+///
+/// # Example
+///
+/// ```rust
+/// use microstr::*;
+/// use core::fmt::Write;
+/// let mut s = MicroStr::<8>::new();
+/// let mut w = MicroStrWriter::new(&mut s);
+/// let (name, id) = ("abcde", 12345);
+/// write!(w, "{name}-{id}").unwrap();
+/// assert_eq!(w.dropped(), 1);
+/// assert_eq!(s.as_str(), "abcde-12");
+/// ```
+pub struct MicroStrWriter<'a, const CAP: usize> {
+    s: &'a mut MicroStr<CAP>,
+    dropped: usize,
+}
+
+impl<'a, const CAP: usize> MicroStrWriter<'a, CAP> {
+    /// Creates a writer appending to `s`.
+    #[inline]
+    pub fn new(s: &'a mut MicroStr<CAP>) -> Self {
+        Self { s, dropped: 0 }
+    }
+
+    /// Returns the number of writes that were truncated or dropped entirely due to overflow.
+    #[inline]
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+}
+
+impl<const CAP: usize> fmt::Write for MicroStrWriter<'_, CAP> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.s.push_str(s).is_err() {
+            self.dropped += 1;
+        }
+        Ok(())
+    }
+}